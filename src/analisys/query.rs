@@ -1,6 +1,10 @@
 use std::path::PathBuf;
 use structopt::StructOpt;
-use workspace_aggregator::analysis::{CodeIndex, DependencyAnalyzer, MetricsAnalyzer};
+use workspace_aggregator::analysis::{CodeIndex, DependencyAnalyzer, MetricsAnalyzer, SymbolKind};
+use workspace_aggregator::cli::suggest::suggest_closest;
+
+/// Every subcommand this binary accepts, used to suggest a fix for a mistyped one.
+const SUBCOMMANDS: [&str; 4] = ["symbol", "metrics", "dependencies", "list-kinds"];
 
 #[derive(StructOpt)]
 #[structopt(name = "workspace-query", about = "Query workspace analysis results")]
@@ -9,6 +13,17 @@ enum Command {
     Symbol {
         /// Symbol name to search for
         name: String,
+        /// Only show references of this kind (e.g. "function", "trait", "const")
+        #[structopt(long)]
+        kind: Option<String>,
+        /// Analysis directory
+        #[structopt(long, default_value = "docs")]
+        analysis_dir: PathBuf,
+    },
+    /// List every indexed symbol of a given kind across the workspace
+    ListKinds {
+        /// Kind to enumerate (e.g. "function", "trait", "const")
+        kind: String,
         /// Analysis directory
         #[structopt(long, default_value = "docs")]
         analysis_dir: PathBuf,
@@ -32,22 +47,72 @@ enum Command {
 }
 
 fn main() -> anyhow::Result<()> {
+    if let Some(first_arg) = std::env::args().nth(1) {
+        if !first_arg.starts_with('-') && !SUBCOMMANDS.contains(&first_arg.as_str()) {
+            if let Some(suggestion) = suggest_closest(&first_arg, &SUBCOMMANDS) {
+                println!("did you mean '{}'?", suggestion);
+            }
+        }
+    }
+
     let cmd = Command::from_args();
 
     match cmd {
-        Command::Symbol { name, analysis_dir } => {
+        Command::Symbol { name, kind, analysis_dir } => {
             let index = CodeIndex::new(&analysis_dir.join("indexes"))?;
 
+            let wanted_kind = match kind.as_deref().map(SymbolKind::parse) {
+                Some(Some(kind)) => Some(kind),
+                Some(None) => {
+                    println!("unrecognized kind '{}'", kind.unwrap());
+                    return Ok(());
+                }
+                None => None,
+            };
+
             if let Some(references) = index.find_symbol(&name)? {
-                println!("References to '{}':", name);
-                for reference in references {
-                    println!("- {}:{}", reference.file, reference.line);
+                let references: Vec<_> = references
+                    .into_iter()
+                    .filter(|reference| wanted_kind.map_or(true, |kind| reference.kind == kind))
+                    .collect();
+
+                if references.is_empty() {
+                    println!("No references found for '{}'", name);
+                } else {
+                    println!("References to '{}':", name);
+                    for reference in references {
+                        println!(
+                            "- {}:{} [{}]",
+                            reference.file.display(),
+                            reference.line,
+                            reference.kind.as_str()
+                        );
+                    }
                 }
             } else {
                 println!("No references found for '{}'", name);
             }
         }
 
+        Command::ListKinds { kind, analysis_dir } => {
+            let index = CodeIndex::new(&analysis_dir.join("indexes"))?;
+
+            let Some(kind) = SymbolKind::parse(&kind) else {
+                println!("unrecognized kind '{}'", kind);
+                return Ok(());
+            };
+
+            let symbols = index.symbols_of_kind(kind);
+            if symbols.is_empty() {
+                println!("No symbols of kind '{}' found", kind.as_str());
+            } else {
+                println!("Symbols of kind '{}':", kind.as_str());
+                for symbol in symbols {
+                    println!("- {} ({}:{})", symbol.qualified_path, symbol.file.display(), symbol.line);
+                }
+            }
+        }
+
         Command::Metrics { file, analysis_dir } => {
             let metrics = MetricsAnalyzer::load_file_metrics(
                 &analysis_dir.join("metrics/code_quality.json"),
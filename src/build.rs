@@ -1,12 +1,17 @@
+use chrono::Local;
+use semver::{BuildMetadata, Prerelease, Version};
+use std::env;
 use std::fs;
 use std::process::Command;
-use std::env;
+use std::time::{SystemTime, UNIX_EPOCH};
 use vergen::EmitBuilder;
 
 
 fn main() {
     let description = "Workspace Aggregator project";  // Replace with your description
-    fs::write(".git/description", description)?;
+    fs::write(".git/description", description).unwrap_or_else(|e| {
+        eprintln!("Failed to write .git/description: {}", e);
+    });
     // Generate build info
     EmitBuilder::builder()
         .build_timestamp()
@@ -14,52 +19,106 @@ fn main() {
         .emit()
         .unwrap_or_else(|e| eprintln!("Failed to generate build info: {}", e));
 
+    emit_version_env();
+
     // Only increment version on release builds
     if env::var("PROFILE").unwrap() == "release" {
         // Check if compilation is triggered by `cargo build` or `cargo run`
         if let Ok(cmd) = env::var("CARGO") {
             if cmd.contains("cargo") {
-                increment_version().unwrap_or_else(|e| {
-                    eprintln!("Failed to increment version: {}", e);
-                });
+                let increment_type =
+                    env::var("WORKSPACE_AGGREGATOR_BUMP").unwrap_or_else(|_| "patch".to_string());
+                let force = env::var("WORKSPACE_AGGREGATOR_FORCE_BUMP").is_ok();
+                match increment_version(&increment_type, force) {
+                    Ok(new_version) => update_changelog(&new_version).unwrap_or_else(|e| {
+                        eprintln!("Failed to update changelog: {}", e);
+                    }),
+                    Err(e) => eprintln!("Failed to increment version: {}", e),
+                }
             }
         }
     }
+
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/index");
+}
+
+/// Emits `GIT_DESCRIBE`/`BUILD_EPOCH` for `version::get_git_describe`/`get_build_date`
+/// to pick up via `option_env!` - kept alongside the version-bump/changelog logic above
+/// since cargo only honors one build script per package.
+fn emit_version_env() {
+    let describe = Command::new("git")
+        .args(["describe", "--tags", "--always", "--dirty"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=GIT_DESCRIBE={}", describe);
+
+    let epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    println!("cargo:rustc-env=BUILD_EPOCH={}", epoch);
 }
 
-fn increment_version() -> Result<(), Box<dyn std::error::Error>> {
+/// Bumps `package.version` in `Cargo.toml` in place.
+///
+/// `increment_type` is one of `major`/`minor`/`patch`/`prerelease`. The three release
+/// levels bump their component and clear `pre`/`build`; `prerelease` instead bumps the
+/// trailing numeric identifier of `version.pre` (`1.2.0-rc.1` -> `1.2.0-rc.2`), starting
+/// at `rc.1` if there's no prerelease yet. Refuses to run if the working tree version is
+/// already ahead of the newest reachable git tag, unless `force` is set - that situation
+/// almost always means a bump already happened and this build is retrying it.
+fn increment_version(increment_type: &str, force: bool) -> Result<String, Box<dyn std::error::Error>> {
     let cargo_toml = fs::read_to_string("Cargo.toml")?;
     let mut doc = cargo_toml.parse::<toml_edit::Document>()?;
 
     let version_str = doc["package"]["version"]
         .as_str()
         .ok_or("Version not found")?;
+    let mut version = Version::parse(version_str)?;
 
-    let mut version_parts: Vec<u32> = version_str
-        .split('.')
-        .map(|s| s.parse().unwrap_or(0))
-        .collect();
+    if let Some(tag_version) = latest_tag_version() {
+        if version > tag_version && !force {
+            return Err(format!(
+                "Cargo.toml version {} is already ahead of the latest tag {} - pass \
+                 WORKSPACE_AGGREGATOR_FORCE_BUMP=1 to bump anyway",
+                version, tag_version
+            )
+            .into());
+        }
+    }
 
     match increment_type {
         "major" => {
-            version_parts[0] += 1;
-            version_parts[1] = 0;
-            version_parts[2] = 0;
-        },
+            version.major += 1;
+            version.minor = 0;
+            version.patch = 0;
+            version.pre = Prerelease::EMPTY;
+            version.build = BuildMetadata::EMPTY;
+        }
         "minor" => {
-            version_parts[1] += 1;
-            version_parts[2] = 0;
-        },
+            version.minor += 1;
+            version.patch = 0;
+            version.pre = Prerelease::EMPTY;
+            version.build = BuildMetadata::EMPTY;
+        }
         "patch" => {
-            version_parts[2] += 1;
-        },
+            version.patch += 1;
+            version.pre = Prerelease::EMPTY;
+            version.build = BuildMetadata::EMPTY;
+        }
+        "prerelease" => {
+            version.pre = bump_prerelease(&version.pre)?;
+        }
         _ => return Err("Invalid increment type".into()),
     }
 
-    // Update version in document
+    let new_version = version.to_string();
     doc["package"]["version"] = toml_edit::value(new_version.clone());
-
-    // Write back to Cargo.toml
     fs::write("Cargo.toml", doc.to_string())?;
 
     // Optional: Create git commit for version bump
@@ -74,7 +133,44 @@ fn increment_version() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     println!("cargo:warning=Version bumped to {}", new_version);
-    Ok(())
+    Ok(new_version)
+}
+
+/// Bumps the trailing numeric identifier of a prerelease tag, e.g. `rc.1` -> `rc.2`;
+/// starts a fresh `rc.1` when there's no prerelease yet.
+fn bump_prerelease(pre: &Prerelease) -> Result<Prerelease, Box<dyn std::error::Error>> {
+    if pre.is_empty() {
+        return Ok(Prerelease::new("rc.1")?);
+    }
+
+    let raw = pre.as_str();
+    let (prefix, number) = raw
+        .rsplit_once('.')
+        .ok_or_else(|| format!("prerelease '{}' has no numeric identifier to bump", raw))?;
+    let next: u64 = number
+        .parse::<u64>()
+        .map_err(|_| format!("prerelease '{}' has no numeric identifier to bump", raw))?
+        + 1;
+    Ok(Prerelease::new(&format!("{}.{}", prefix, next))?)
+}
+
+/// The newest reachable tag (`git describe --tags --abbrev=0`), or `None` if the
+/// repository has no tags yet.
+fn latest_tag() -> Option<String> {
+    let output = Command::new("git")
+        .args(&["describe", "--tags", "--abbrev=0"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// `latest_tag`, parsed as a bare semver version; tags are expected in `vX.Y.Z` form.
+/// `None` if there's no tag yet or it doesn't parse as semver.
+fn latest_tag_version() -> Option<Version> {
+    Version::parse(latest_tag()?.trim_start_matches('v')).ok()
 }
 
 fn is_git_repo() -> Result<bool, std::io::Error> {
@@ -85,24 +181,111 @@ fn is_git_repo() -> Result<bool, std::io::Error> {
         .success())
 }
 
+#[derive(Default)]
+struct ChangelogGroups {
+    breaking: Vec<String>,
+    features: Vec<String>,
+    fixes: Vec<String>,
+    performance: Vec<String>,
+    other: Vec<String>,
+}
+
+/// Collects commits since the last tag, groups them Conventional-Commit-style, and
+/// prepends a Keep-a-Changelog section for `new_version` to `CHANGELOG.md`.
 fn update_changelog(new_version: &str) -> Result<(), Box<dyn std::error::Error>> {
-    let mut changelog = String::from(format!("## [{}] - {}\n",
-        new_version,
-        Local::now().format("%Y-%m-%d")
-    ));
+    let range = match latest_tag() {
+        Some(tag) => format!("{}..HEAD", tag),
+        None => "HEAD".to_string(),
+    };
 
-    // Get git commits since last tag
+    // %x01/%x02 separate commit fields/records without colliding with commit text.
     let output = Command::new("git")
-        .args(&["log", "--pretty=format:- %s", "HEAD...HEAD^"])
+        .args(&["log", &range, "--pretty=format:%s%x01%b%x02"])
         .output()?;
+    let log = String::from_utf8_lossy(&output.stdout);
+
+    let mut groups = ChangelogGroups::default();
+    for record in log.split('\x02') {
+        let record = record.trim();
+        if record.is_empty() {
+            continue;
+        }
+
+        let mut fields = record.splitn(2, '\x01');
+        let subject = fields.next().unwrap_or("").trim();
+        let body = fields.next().unwrap_or("").trim();
 
-    changelog.push_str(&String::from_utf8_lossy(&output.stdout));
-    changelog.push_str("\n\n");
+        // Skip the bump commits this same build produces, or they'd show up in
+        // every future changelog section once they're reachable from HEAD.
+        if subject.starts_with("chore: bump version") {
+            continue;
+        }
+
+        let (commit_type, scope, description, breaking_bang) = parse_conventional_commit(subject);
+        let entry = match scope {
+            Some(scope) => format!("- **{}**: {}", scope, description),
+            None => format!("- {}", description),
+        };
+
+        if breaking_bang || body.contains("BREAKING CHANGE") {
+            groups.breaking.push(entry);
+        } else {
+            match commit_type {
+                Some("feat") => groups.features.push(entry),
+                Some("fix") => groups.fixes.push(entry),
+                Some("perf") => groups.performance.push(entry),
+                _ => groups.other.push(entry),
+            }
+        }
+    }
+
+    let mut section = format!(
+        "## [{}] - {}\n\n",
+        new_version,
+        Local::now().format("%Y-%m-%d")
+    );
+    push_section(&mut section, "Breaking Changes", &groups.breaking);
+    push_section(&mut section, "Features", &groups.features);
+    push_section(&mut section, "Bug Fixes", &groups.fixes);
+    push_section(&mut section, "Performance", &groups.performance);
+    push_section(&mut section, "Other", &groups.other);
 
-    // Prepend to CHANGELOG.md
     let existing = fs::read_to_string("CHANGELOG.md").unwrap_or_default();
-    fs::write("CHANGELOG.md", format!("{}{}", changelog, existing))?;
+    fs::write("CHANGELOG.md", format!("{}{}", section, existing))?;
 
     Ok(())
 }
 
+fn push_section(out: &mut String, heading: &str, entries: &[String]) {
+    if entries.is_empty() {
+        return;
+    }
+    out.push_str(&format!("### {}\n\n", heading));
+    for entry in entries {
+        out.push_str(entry);
+        out.push('\n');
+    }
+    out.push('\n');
+}
+
+/// Splits a Conventional Commit subject (`type(scope)!: description`) into
+/// `(type, scope, description, breaking_bang)`. Subjects that don't follow the
+/// convention come back as `(None, None, subject, false)` and land in "Other".
+fn parse_conventional_commit(subject: &str) -> (Option<&str>, Option<&str>, &str, bool) {
+    let Some((header, description)) = subject.split_once(": ") else {
+        return (None, None, subject, false);
+    };
+
+    let (type_and_scope, breaking_bang) = match header.strip_suffix('!') {
+        Some(stripped) => (stripped, true),
+        None => (header, false),
+    };
+
+    let (commit_type, scope) = match type_and_scope.split_once('(') {
+        Some((commit_type, rest)) => (commit_type, rest.strip_suffix(')')),
+        None => (type_and_scope, None),
+    };
+
+    (Some(commit_type), scope, description, breaking_bang)
+}
+
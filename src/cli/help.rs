@@ -66,6 +66,11 @@ tree        Directory structure             tree_YYYYMMDD_HHMMSS.txt
 summary     Project overview                summary_YYYYMMDD_HHMMSS.txt
 meta        JSON metadata                   meta_YYYYMMDD_HHMMSS.json
 llm         LLM-optimized format           llm_YYYYMMDD_HHMMSS.md
+html        Browsable HTML report          html_report_YYYYMMDD_HHMMSS/
+dist        Gzipped tarball of all outputs  workspace_YYYYMMDD_HHMMSS.tar.gz
+diagnostics SARIF/GitHub CI diagnostics     diagnostics_YYYYMMDD_HHMMSS.sarif.json
+json        Machine-readable JSON/NDJSON report report_YYYYMMDD_HHMMSS.json
+git         Per-file git status, branch & HEAD  git_YYYYMMDD_HHMMSS.json
 
 {}
 {}
@@ -78,10 +83,74 @@ $ workspace-aggregator . --exclude md,txt
 $ workspace-aggregator . --exclude-dir test,temp
 $ workspace-aggregator . --respect-gitignore
 
+# Exclude files via a dedicated .ignore/.agregatorignore list without touching VCS rules
+$ workspace-aggregator . --respect-gitignore --no-vcs-ignore
+$ workspace-aggregator . --no-ignore
+
+# Limit aggregation to named file-type groups, or exclude one
+$ workspace-aggregator . --type rust,web
+$ workspace-aggregator . --type-not config --type-add proto:*.proto3
+
+# Annotate the tree output with each entry's git status
+$ workspace-aggregator . --output tree --git-status
+
+# Size LLM chunks against a real BPE merge table instead of the chars/4 estimate
+$ workspace-aggregator . --output llm --token-vocab-file bpe_merges.txt
+
 # Display Options
 $ workspace-aggregator . --verbose
 $ workspace-aggregator . --quiet
 
+# Browsable HTML report with live reload
+$ workspace-aggregator serve . --output-dir ./docs/html_report --port 8080
+
+# Machine-readable summary for CI/dashboards
+$ workspace-aggregator . --generate summary --summary-format json-compact
+
+# Bounded-memory analysis for huge workspaces
+$ workspace-aggregator . --strategy less-memory
+
+# Cap the parallel analysis pool instead of using all available cores
+$ workspace-aggregator . --threads 4
+
+# Capture full Trace/Debug logs on disk without flooding the terminal
+$ workspace-aggregator . --verbosity trace --log-dir ./logs --log-max-bytes 1048576 --log-max-files 5
+
+# Bundle every artifact into one archive for CI release attachments
+$ workspace-aggregator . --generate workspace,tree,summary,meta,dist
+
+# Annotate a CI run with complexity hotspots and processing errors
+$ workspace-aggregator . --generate diagnostics --diagnostics-format github --complexity-threshold 15
+
+# Register a GitHub Actions problem matcher for inline annotations on the raw diagnostics stream
+$ workspace-aggregator . --generate diagnostics --diagnostics-format problem-matcher --comment-ratio-threshold 0.05
+
+# Re-scan a large workspace cheaply by skipping files whose (mtime, size) haven't changed
+$ workspace-aggregator . --stats-cache ./docs/.stats_cache
+
+# Same, but let the cache default to a file alongside --output-dir
+$ workspace-aggregator . --output-dir ./docs --stats-cache
+
+# Render the files list as a directory tree with rolled-up metrics, hotspots first
+$ workspace-aggregator . --generate files --files-format tree --sort-by complexity --max-depth 3 --prune-below 5
+
+# Export the full analysis for CI/dashboards to ingest, one record per file
+$ workspace-aggregator . --generate json --json-format ndjson
+
+# Share a team-wide aggregation profile via workspace-aggregator.toml instead of
+# retyping long --exclude/--generate lists (discovered by walking up from the
+# scanned directory to $HOME, or point at one explicitly)
+$ workspace-aggregator . --config ./workspace-aggregator.toml
+
+# Snapshot per-file git status and branch/HEAD info alongside the rest of the export
+$ workspace-aggregator . --generate git,meta
+
+# Keep re-aggregating on every save, clearing the screen between runs
+$ workspace-aggregator . --watch --clear --watch-delay 300
+
+# Re-include a path an earlier gitignore/exclude-pattern rule excluded
+$ workspace-aggregator . --respect-gitignore --override '!vendor/important.rs'
+
 {}
 {}
 project/
@@ -123,6 +192,16 @@ pub fn print_version() {
     println!("\n{}", "=".repeat(50).bright_green());
     println!("{}", "📦 workspace-aggregator".bright_green().bold());
     println!("🔖 Version: {}", version::get_version().bright_yellow());
+    println!(
+        "🧬 Build: {}",
+        format!(
+            "workspace-aggregator {} ({} {})",
+            version::get_version(),
+            version::get_git_describe(),
+            version::get_build_date()
+        )
+        .bright_black()
+    );
     println!("🦀 Built with Rust 2021 Edition");
     println!("👤 Author: Guilherme Baufaker Rêgo");
     println!("📧 Contact: baufaker@protonmail.com");
@@ -135,7 +214,7 @@ pub fn print_short_help() {
     println!("  workspace-aggregator <directory> [options]");
     println!("\n{}:", "Common options".yellow().bold());
     println!(
-        "  --generate <types>    Specify outputs (workspace,files,tree,stats,summary,meta,llm)"
+        "  --generate <types>    Specify outputs (workspace,files,tree,stats,summary,meta,llm,json)"
     );
     println!("  --output-dir <path>   Set output directory");
     println!("  --exclude <exts>      Exclude file extensions");
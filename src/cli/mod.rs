@@ -6,3 +6,6 @@
 //! - Quick help for common usage
 
 pub mod help;
+pub mod serve;
+pub mod suggest;
+pub mod watch;
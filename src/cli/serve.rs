@@ -0,0 +1,193 @@
+//! `workspace-aggregator serve` - generates the HTML report once, then serves it
+//! over a small local HTTP server and regenerates it whenever a source file changes.
+
+use crate::config::{Config, OutputType};
+use crate::processor::FileProcessor;
+use std::io::{self, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, UNIX_EPOCH};
+
+pub const DEFAULT_PORT: u16 = 8080;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+pub fn run(dir: PathBuf, output_dir: PathBuf, port: u16) -> io::Result<()> {
+    generate_once(&dir, &output_dir)?;
+
+    let watch_dir = dir.clone();
+    let watch_output_dir = output_dir.clone();
+    thread::spawn(move || watch_and_regenerate(watch_dir, watch_output_dir));
+
+    println!(
+        "📡 Serving HTML report at http://127.0.0.1:{} (watching {} for changes)",
+        port,
+        dir.display()
+    );
+
+    let listener = TcpListener::bind(("127.0.0.1", port))?;
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                let root = output_dir.clone();
+                thread::spawn(move || {
+                    if let Err(e) = handle_connection(stream, &root) {
+                        eprintln!("⚠️  Request failed: {}", e);
+                    }
+                });
+            }
+            Err(e) => eprintln!("⚠️  Connection failed: {}", e),
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_once(dir: &Path, output_dir: &Path) -> io::Result<()> {
+    let mut config = Config::default();
+    config.dir_path = dir.to_path_buf();
+    config.quiet = true;
+    config.generated_types.insert(OutputType::Html);
+    config.output_config.output_dir = Some(output_dir.to_path_buf());
+
+    FileProcessor::new(config).process()
+}
+
+fn watch_and_regenerate(dir: PathBuf, output_dir: PathBuf) {
+    let mut last_seen = latest_mtime(&dir);
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let current = latest_mtime(&dir);
+        if current != last_seen {
+            last_seen = current;
+            println!("🔄 Change detected, regenerating report...");
+            if let Err(e) = generate_once(&dir, &output_dir) {
+                eprintln!("⚠️  Regeneration failed: {}", e);
+            }
+        }
+    }
+}
+
+fn latest_mtime(dir: &Path) -> u64 {
+    let mut latest = 0u64;
+    collect_latest_mtime(dir, &mut latest);
+    latest
+}
+
+fn collect_latest_mtime(dir: &Path, latest: &mut u64) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if matches!(
+                path.file_name().and_then(|n| n.to_str()),
+                Some("target" | ".git" | "node_modules")
+            ) {
+                continue;
+            }
+            collect_latest_mtime(&path, latest);
+        } else if let Ok(metadata) = entry.metadata() {
+            if let Ok(modified) = metadata.modified() {
+                if let Ok(secs) = modified.duration_since(UNIX_EPOCH) {
+                    *latest = (*latest).max(secs.as_secs());
+                }
+            }
+        }
+    }
+}
+
+fn handle_connection(mut stream: TcpStream, root: &Path) -> io::Result<()> {
+    let mut buf = [0u8; 4096];
+    let n = stream.read(&mut buf)?;
+    let request = String::from_utf8_lossy(&buf[..n]);
+    let path = request
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .unwrap_or("/");
+
+    let mut relative = path.trim_start_matches('/');
+    if relative.is_empty() {
+        relative = "index.html";
+    }
+    let file_path = root.join(relative);
+
+    if let Some(contents) = read_within_root(root, &file_path) {
+        let header = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\n\r\n",
+            content_type_for(&file_path),
+            contents.len()
+        );
+        stream.write_all(header.as_bytes())?;
+        stream.write_all(&contents)?;
+    } else {
+        let body = b"404 Not Found";
+        let header = format!(
+            "HTTP/1.1 404 Not Found\r\nContent-Length: {}\r\n\r\n",
+            body.len()
+        );
+        stream.write_all(header.as_bytes())?;
+        stream.write_all(body)?;
+    }
+
+    Ok(())
+}
+
+/// Reads `file_path`'s contents, but only if it canonicalizes to somewhere inside
+/// `root` - `relative`'s `trim_start_matches('/')` alone doesn't stop a `../`
+/// segment from walking the joined path out of `root` (e.g. `GET /../../etc/passwd`),
+/// so both sides are canonicalized and checked before anything is read.
+fn read_within_root(root: &Path, file_path: &Path) -> Option<Vec<u8>> {
+    let root = std::fs::canonicalize(root).ok()?;
+    let resolved = std::fs::canonicalize(file_path).ok()?;
+    if !resolved.starts_with(&root) {
+        return None;
+    }
+    std::fs::read(&resolved).ok()
+}
+
+fn content_type_for(path: &Path) -> &'static str {
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("html") => "text/html; charset=utf-8",
+        Some("svg") => "image/svg+xml",
+        Some("css") => "text/css",
+        Some("js") => "application/javascript",
+        Some("json") => "application/json",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `relative`'s `trim_start_matches('/')` alone let a request like
+    /// `GET /../../../etc/passwd` join its way out of `root` entirely; this
+    /// exercises the same join `handle_connection` does, then asserts it's refused.
+    #[test]
+    fn read_within_root_rejects_path_traversal() {
+        let dir = std::env::temp_dir().join(format!(
+            "workspace_aggregator_serve_test_{}",
+            std::process::id()
+        ));
+        let served_dir = dir.join("served");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&served_dir).unwrap();
+        std::fs::write(dir.join("secret.txt"), "top secret").unwrap();
+        std::fs::write(served_dir.join("index.html"), "hello").unwrap();
+
+        let escape_path = served_dir.join("../secret.txt");
+        assert!(read_within_root(&served_dir, &escape_path).is_none());
+
+        let in_root_path = served_dir.join("index.html");
+        assert_eq!(
+            read_within_root(&served_dir, &in_root_path),
+            Some(b"hello".to_vec())
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,38 @@
+//! "Did you mean ...?" suggestions for mistyped CLI tokens, the same ergonomics
+//! cargo uses for unknown subcommands.
+
+/// Classic two-row dynamic-programming Levenshtein edit distance between `a` and `b`.
+pub fn lev_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut prev_row: Vec<usize> = (0..=b.len()).collect();
+    let mut curr_row = vec![0; b.len() + 1];
+
+    for i in 1..=a.len() {
+        curr_row[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            curr_row[j] = (prev_row[j] + 1)
+                .min(curr_row[j - 1] + 1)
+                .min(prev_row[j - 1] + cost);
+        }
+        std::mem::swap(&mut prev_row, &mut curr_row);
+    }
+
+    prev_row[b.len()]
+}
+
+/// Returns the closest entry in `candidates` to `input`, if its edit distance is
+/// within a generous-but-not-silly threshold (`<= 3` or `<= input.len() / 3`,
+/// whichever is larger) - close enough to be a typo, not a different word entirely.
+pub fn suggest_closest<'a>(input: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let threshold = (input.chars().count() / 3).max(3);
+
+    candidates
+        .iter()
+        .map(|candidate| (*candidate, lev_distance(input, candidate)))
+        .filter(|(_, distance)| *distance <= threshold)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| candidate)
+}
@@ -0,0 +1,91 @@
+//! `--watch` mode: keeps polling `dir_path` after the initial run and re-aggregates
+//! whenever a file changes, coalescing bursts behind `--watch-delay` so a save-all
+//! or a branch checkout triggers one rebuild instead of dozens.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::config::Config;
+use crate::processor::FileProcessor;
+
+/// How often the filesystem is polled for changes while idle.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Runs the initial aggregation, then watches `config.dir_path` and re-runs it on
+/// every subsequent change until the process is killed.
+pub fn run(config: Config) -> std::io::Result<()> {
+    FileProcessor::new(config.clone()).process()?;
+
+    let watch_delay = Duration::from_millis(config.watch_delay_ms);
+
+    println!(
+        "👀 Watching {} for changes (delay {}ms)...",
+        config.dir_path.display(),
+        config.watch_delay_ms
+    );
+
+    let mut last_snapshot = snapshot(&config);
+    let mut pending_since: Option<Instant> = None;
+
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+
+        let current = snapshot(&config);
+        if current != last_snapshot {
+            last_snapshot = current;
+            pending_since.get_or_insert_with(Instant::now);
+        }
+
+        let Some(since) = pending_since else {
+            continue;
+        };
+        if since.elapsed() < watch_delay {
+            continue;
+        }
+        pending_since = None;
+
+        if config.clear_on_watch {
+            print!("\x1B[2J\x1B[1;1H");
+        }
+        println!("🔄 Change detected, regenerating...");
+        if let Err(e) = FileProcessor::new(config.clone()).process() {
+            eprintln!("⚠️  Regeneration failed: {}", e);
+        }
+    }
+}
+
+fn snapshot(config: &Config) -> HashMap<PathBuf, SystemTime> {
+    let mut map = HashMap::new();
+    collect(&config.dir_path, config, &mut map);
+    map
+}
+
+/// Ignore decisions go through `Config::is_ignored` - the same authoritative source
+/// the main traversal uses - rather than this module building its own gitignore/
+/// override matchers.
+fn collect(dir: &Path, config: &Config, map: &mut HashMap<PathBuf, SystemTime>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if config.is_ignored(&path) {
+            continue;
+        }
+        if path.is_dir() {
+            collect(&path, config, map);
+            continue;
+        }
+        if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+            if config.exclude_extensions.contains(&ext.to_lowercase()) {
+                continue;
+            }
+        }
+        if let Ok(metadata) = entry.metadata() {
+            if let Ok(modified) = metadata.modified() {
+                map.insert(path, modified);
+            }
+        }
+    }
+}
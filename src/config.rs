@@ -1,9 +1,13 @@
 use crate::cli::help::{print_help, print_short_help, print_version};
+use crate::cli::suggest::suggest_closest;
+use crate::filters::ignore_config::IgnoreConfig;
 use chrono::Local;
+use serde::Deserialize;
 use std::collections::{HashMap, HashSet};
 use std::env;
+use std::fs;
 use std::io::{self, Error, ErrorKind};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone)]
 pub enum OutputType {
@@ -13,6 +17,18 @@ pub enum OutputType {
     Summary,
     Meta,
     LLMFormat,
+    Security,
+    Html,
+    /// A single `.tar.gz` bundling whichever other output types were also requested
+    /// via `--generate` (matched by filename prefix; `Html`'s directory output isn't
+    /// bundled).
+    Dist,
+    /// Complexity hotspots and processing errors as CI-consumable diagnostics.
+    Diagnostics,
+    /// The full analysis snapshot as machine-readable JSON or NDJSON.
+    Json,
+    /// Per-file git status plus branch/HEAD metadata, via `git2`.
+    Git,
 }
 
 #[derive(Debug, Clone, PartialEq, Ord, PartialOrd, Eq, Default)]
@@ -25,6 +41,125 @@ pub enum VerbosityLevel {
     Trace,
 }
 
+/// Selects how `FileProcessor` trades memory for accuracy on large workspaces.
+///
+/// The "Most Complex Files" ranking and the "needs docs" count are exact in both
+/// modes - the former because `file_statistics` is always pruned down to a top-N
+/// that's a superset of what gets shown, the latter because it's tracked as a
+/// running counter rather than derived from `file_statistics`. Everything else
+/// that reads the full per-file map - duplicate detection, "largest files",
+/// git hotspots - only sees the bounded top-N survivors under `LessMemory` and so
+/// requires `LessTime` for exact results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnalysisStrategy {
+    /// Keep every file's stats in memory for exact top-N results (current behavior).
+    #[default]
+    LessTime,
+    /// Stream files one at a time and keep only bounded top-N collections, trading
+    /// exactness for a flat memory footprint on huge repositories.
+    LessMemory,
+}
+
+/// Every token `--generate` accepts, used to suggest a fix for unknown ones.
+const VALID_OUTPUT_TYPES: [&str; 12] = [
+    "workspace",
+    "files",
+    "tree",
+    "summary",
+    "meta",
+    "llm",
+    "security",
+    "html",
+    "dist",
+    "diagnostics",
+    "json",
+    "git",
+];
+
+/// Every flag the CLI loop below recognizes, used to suggest a fix for a mistyped
+/// one instead of silently ignoring it.
+const VALID_FLAGS: [&str; 39] = [
+    "--generate",
+    "--output-dir",
+    "--exclude",
+    "--exclude-dir",
+    "--exclude-pattern",
+    "--verbose",
+    "--quiet",
+    "--respect-gitignore",
+    "--no-vcs-ignore",
+    "--no-ignore",
+    "--git-status",
+    "--verbosity",
+    "--config",
+    "--progress-style",
+    "--languages-file",
+    "--token-vocab-file",
+    "--stats-cache",
+    "--format",
+    "--summary-format",
+    "--strategy",
+    "--diagnostics-format",
+    "--complexity-threshold",
+    "--comment-ratio-threshold",
+    "--files-format",
+    "--max-depth",
+    "--sort-by",
+    "--prune-below",
+    "--threads",
+    "--log-dir",
+    "--log-max-bytes",
+    "--log-max-files",
+    "--json-format",
+    "--type",
+    "--type-not",
+    "--type-add",
+    "--watch",
+    "--watch-delay",
+    "--clear",
+    "--override",
+];
+
+impl AnalysisStrategy {
+    pub fn parse(value: &str) -> Self {
+        match value.to_lowercase().as_str() {
+            "less-memory" => AnalysisStrategy::LessMemory,
+            _ => AnalysisStrategy::LessTime,
+        }
+    }
+}
+
+/// Shared by `--generate` and the `generate` key in `workspace-aggregator.toml`.
+fn parse_output_type(name: &str) -> Option<OutputType> {
+    match name.trim() {
+        "workspace" => Some(OutputType::Workspace),
+        "files" => Some(OutputType::Files),
+        "tree" => Some(OutputType::Tree),
+        "summary" => Some(OutputType::Summary),
+        "meta" => Some(OutputType::Meta),
+        "llm" => Some(OutputType::LLMFormat),
+        "security" => Some(OutputType::Security),
+        "html" => Some(OutputType::Html),
+        "dist" => Some(OutputType::Dist),
+        "diagnostics" => Some(OutputType::Diagnostics),
+        "json" => Some(OutputType::Json),
+        "git" => Some(OutputType::Git),
+        _ => None,
+    }
+}
+
+/// Shared by `--verbosity` and the `verbosity` key in `workspace-aggregator.toml`.
+fn parse_verbosity(value: &str) -> VerbosityLevel {
+    match value.to_lowercase().as_str() {
+        "error" => VerbosityLevel::Error,
+        "warn" => VerbosityLevel::Warn,
+        "info" => VerbosityLevel::Info,
+        "debug" => VerbosityLevel::Debug,
+        "trace" => VerbosityLevel::Trace,
+        _ => VerbosityLevel::Info,
+    }
+}
+
 #[derive(Clone, Default)]
 pub struct Config {
     pub dir_path: PathBuf,
@@ -35,9 +170,56 @@ pub struct Config {
     pub quiet: bool,
     pub progress_style: String,
     pub respect_gitignore: bool,
+    /// Skip `.gitignore` while still honoring `.ignore`/`.agregatorignore`.
+    pub no_vcs_ignore: bool,
+    /// Skip every auto-loaded ignore file (`.gitignore`, `.ignore`, `.agregatorignore`).
+    pub no_ignore: bool,
+    /// `--type` whitelist of named file types (e.g. `rust`, `web`).
+    pub type_only: Vec<String>,
+    /// `--type-not` blacklist of named file types.
+    pub type_not: Vec<String>,
+    /// `--type-add name:glob` user-defined/extended file types.
+    pub type_add: Vec<(String, String)>,
+    /// Annotate `OutputType::Tree` entries with their git status.
+    pub show_git_status: bool,
     pub generated_types: HashSet<OutputType>,
     pub output_config: OutputConfig,
     pub verbosity: VerbosityLevel,
+    pub languages_file: Option<PathBuf>,
+    /// BPE merge-rank table for `OutputType::Llm`'s token-count estimates.
+    pub token_vocab_file: Option<PathBuf>,
+    /// On-disk `(mtime, size)`-keyed cache of per-file complexity/line metrics. When
+    /// set, a scan reuses cached metrics for any file whose pair hasn't changed and
+    /// rewrites the cache (pruning files no longer seen) at the end of the run.
+    pub stats_cache_file: Option<PathBuf>,
+    pub analysis_strategy: AnalysisStrategy,
+    /// Worker threads for the parallel analysis pass. `None` (default) uses rayon's
+    /// own default, which is `std::thread::available_parallelism()`.
+    pub thread_count: Option<usize>,
+    /// When set, `FileProcessor::log` also appends timestamped lines to a rotating
+    /// log file under this directory, independent of what `--verbose`/`--quiet`
+    /// sends to the terminal.
+    pub log_dir: Option<PathBuf>,
+    /// Size in bytes at which a log file under `log_dir` rolls over. Defaults to 4 MB.
+    pub log_max_bytes: Option<u64>,
+    /// Number of rotated log files to keep under `log_dir`. Defaults to 3.
+    pub log_max_files: Option<usize>,
+    /// Keep running after the initial pass, re-aggregating whenever a file under
+    /// `dir_path` changes.
+    pub watch: bool,
+    /// Milliseconds a burst of filesystem changes is coalesced before `--watch`
+    /// triggers a rebuild. Defaults to 200.
+    pub watch_delay_ms: u64,
+    /// Clear the screen before each `--watch` re-run.
+    pub clear_on_watch: bool,
+    /// User glob overrides from `--override`, in addition to `--exclude-pattern`
+    /// (both feed the same matcher inside `ignore_config`). A leading `!` re-includes
+    /// a path, matching gitignore negation.
+    pub override_patterns: HashSet<String>,
+    /// The resolved `.gitignore`/`.ignore`/overrides matcher, built once here so
+    /// traversal code has one authoritative `is_ignored` to call. See
+    /// [`Config::is_ignored`].
+    pub ignore_config: IgnoreConfig,
 }
 
 #[derive(Clone, Default)]
@@ -45,6 +227,36 @@ pub struct OutputConfig {
     pub output_dir: Option<PathBuf>,
     pub outputs: HashMap<OutputType, PathBuf>,
     pub use_timestamp: bool,
+    /// Format for `OutputType::Security`: "sarif" (default) or "github".
+    pub security_format: String,
+    /// Format for `OutputType::Summary`: "pretty" (default), "json" or "json-compact".
+    pub summary_format: String,
+    /// Format for `OutputType::Diagnostics`: "sarif" (default), "github", or
+    /// "problem-matcher" (a `path:line: severity: message` stream plus a companion
+    /// GitHub Actions problem-matcher JSON file).
+    pub diagnostics_format: String,
+    /// Cyclomatic complexity above which a file is reported as a hotspot in
+    /// `OutputType::Diagnostics`.
+    pub complexity_threshold: f64,
+    /// Comment ratio below which a file is reported as under-documented in
+    /// `OutputType::Diagnostics`.
+    pub comment_ratio_threshold: f64,
+    /// Format for `OutputType::Files`: "flat" (default, per-extension buckets) or
+    /// "tree" (directory hierarchy with rolled-up per-node metrics).
+    pub files_format: String,
+    /// In `"tree"` mode, directories deeper than this from the base path are
+    /// collapsed into their parent's rolled-up totals. `None` means unlimited.
+    pub files_max_depth: Option<usize>,
+    /// In `"tree"` mode, the metric siblings are sorted by (descending) so hotspots
+    /// float to the top: "name" (default, alphabetical), "size", "lines", or
+    /// "complexity".
+    pub files_sort_by: String,
+    /// In `"tree"` mode, directories whose rolled-up `files_sort_by` metric falls
+    /// below this are pruned from the rendered tree entirely.
+    pub files_prune_below: Option<f64>,
+    /// Format for `OutputType::Json`: "object" (default, pretty-printed single
+    /// object), "object-compact" (same, one line), or "ndjson" (one record per file).
+    pub json_format: String,
 }
 
 impl Config {
@@ -81,11 +293,75 @@ impl Config {
             quiet: false,
             progress_style: String::from("detailed"),
             respect_gitignore: false,
+            no_vcs_ignore: false,
+            no_ignore: false,
+            type_only: Vec::new(),
+            type_not: Vec::new(),
+            type_add: Vec::new(),
+            show_git_status: false,
             generated_types: HashSet::new(),
-            output_config: OutputConfig::default(),
+            output_config: OutputConfig {
+                security_format: "sarif".to_string(),
+                summary_format: "pretty".to_string(),
+                diagnostics_format: "sarif".to_string(),
+                complexity_threshold: 10.0,
+                comment_ratio_threshold: 0.05,
+                files_format: "flat".to_string(),
+                files_max_depth: None,
+                files_sort_by: "name".to_string(),
+                files_prune_below: None,
+                json_format: "object".to_string(),
+                ..OutputConfig::default()
+            },
             verbosity: VerbosityLevel::Info,
+            languages_file: None,
+            token_vocab_file: None,
+            stats_cache_file: None,
+            analysis_strategy: AnalysisStrategy::LessTime,
+            thread_count: None,
+            log_dir: None,
+            log_max_bytes: None,
+            log_max_files: None,
+            watch: false,
+            watch_delay_ms: 200,
+            clear_on_watch: false,
+            override_patterns: HashSet::new(),
+            ignore_config: IgnoreConfig::default(),
         };
 
+        // Layer in `workspace-aggregator.toml` before the CLI loop below runs, so
+        // any flag the user actually typed still overrides a value the file set -
+        // the CLI loop unconditionally assigns on a matching flag regardless of
+        // what's already in `config`.
+        let explicit_config_path = args
+            .iter()
+            .position(|a| a == "--config")
+            .and_then(|idx| args.get(idx + 1))
+            .map(PathBuf::from);
+        let scan_start = args
+            .iter()
+            .skip(1)
+            .find(|a| !a.starts_with("--"))
+            .map(PathBuf::from);
+        let config_file_path = explicit_config_path
+            .or_else(|| scan_start.as_deref().and_then(discover_config_file));
+
+        if let Some(config_file_path) = config_file_path {
+            match Config::from_file(&config_file_path) {
+                Ok(file_config) => {
+                    println!("Debug: Loaded config file: {}", config_file_path.display());
+                    file_config.apply_to(&mut config);
+                }
+                Err(e) => {
+                    println!(
+                        "Warning: Failed to load config file {}: {}",
+                        config_file_path.display(),
+                        e
+                    );
+                }
+            }
+        }
+
         let mut i = 1;
         while i < args.len() {
             match args[i].as_str() {
@@ -93,27 +369,22 @@ impl Config {
                     i += 1;
                     if i < args.len() {
                         for typ in args[i].split(',') {
-                            match typ.trim() {
-                                "workspace" => {
-                                    config.generated_types.insert(OutputType::Workspace);
-                                }
-                                "files" => {
-                                    config.generated_types.insert(OutputType::Files);
-                                }
-                                "tree" => {
-                                    config.generated_types.insert(OutputType::Tree);
+                            let trimmed = typ.trim();
+                            match parse_output_type(trimmed) {
+                                Some(output_type) => {
+                                    config.generated_types.insert(output_type);
                                 }
-                                "summary" => {
-                                    config.generated_types.insert(OutputType::Summary);
-                                }
-                                "meta" => {
-                                    config.generated_types.insert(OutputType::Meta);
-                                }
-                                "llm" => {
-                                    config.generated_types.insert(OutputType::LLMFormat);
-                                }
-                                _ => {
-                                    println!("Warning: Unknown output type: {}", typ);
+                                None => {
+                                    if let Some(suggestion) =
+                                        suggest_closest(trimmed, &VALID_OUTPUT_TYPES)
+                                    {
+                                        println!(
+                                            "Warning: Unknown output type: {} - did you mean '{}'?",
+                                            trimmed, suggestion
+                                        );
+                                    } else {
+                                        println!("Warning: Unknown output type: {}", trimmed);
+                                    }
                                 }
                             }
                         }
@@ -162,6 +433,14 @@ impl Config {
                         println!("Debug: Excluding patterns: {:?}", config.exclude_patterns);
                     }
                 }
+                "--override" => {
+                    i += 1;
+                    if i < args.len() {
+                        config.override_patterns =
+                            args[i].split(',').map(|s| s.trim().to_string()).collect();
+                        println!("Debug: Override patterns: {:?}", config.override_patterns);
+                    }
+                }
                 "--verbose" => {
                     config.verbose = true;
                     config.verbosity = VerbosityLevel::Debug;
@@ -172,25 +451,207 @@ impl Config {
                 "--respect-gitignore" => {
                     config.respect_gitignore = true;
                 }
+                "--no-vcs-ignore" => {
+                    config.no_vcs_ignore = true;
+                }
+                "--no-ignore" => {
+                    config.no_ignore = true;
+                }
+                "--git-status" => {
+                    config.show_git_status = true;
+                }
                 "--verbosity" => {
                     i += 1;
                     if i < args.len() {
-                        config.verbosity = match args[i].to_lowercase().as_str() {
-                            "error" => VerbosityLevel::Error,
-                            "warn" => VerbosityLevel::Warn,
-                            "info" => VerbosityLevel::Info,
-                            "debug" => VerbosityLevel::Debug,
-                            "trace" => VerbosityLevel::Trace,
-                            _ => VerbosityLevel::Info,
-                        };
+                        config.verbosity = parse_verbosity(&args[i]);
                     }
                 }
+                "--config" => {
+                    // The path is resolved and applied before this loop runs (so file
+                    // values are in place as defaults before any CLI flag can
+                    // override them); just skip over its value here.
+                    i += 1;
+                }
                 "--progress-style" => {
                     i += 1;
                     if i < args.len() {
                         config.progress_style = args[i].to_string();
                     }
                 }
+                "--languages-file" => {
+                    i += 1;
+                    if i < args.len() {
+                        config.languages_file = Some(PathBuf::from(&args[i]));
+                    }
+                }
+                "--token-vocab-file" => {
+                    i += 1;
+                    if i < args.len() {
+                        config.token_vocab_file = Some(PathBuf::from(&args[i]));
+                    }
+                }
+                "--stats-cache" => {
+                    // A bare flag (no path, or immediately followed by another flag)
+                    // defaults to a cache file alongside the configured output
+                    // directory, so enabling incremental re-analysis doesn't require
+                    // also picking a location for its manifest.
+                    if i + 1 < args.len() && !args[i + 1].starts_with("--") {
+                        i += 1;
+                        config.stats_cache_file = Some(PathBuf::from(&args[i]));
+                    } else {
+                        let dir = config
+                            .output_config
+                            .output_dir
+                            .clone()
+                            .unwrap_or_else(|| PathBuf::from("."));
+                        config.stats_cache_file = Some(dir.join(".workspace-aggregator-cache"));
+                    }
+                }
+                "--format" => {
+                    i += 1;
+                    if i < args.len() {
+                        config.output_config.security_format = args[i].to_lowercase();
+                    }
+                }
+                "--summary-format" => {
+                    i += 1;
+                    if i < args.len() {
+                        config.output_config.summary_format = args[i].to_lowercase();
+                    }
+                }
+                "--strategy" => {
+                    i += 1;
+                    if i < args.len() {
+                        config.analysis_strategy = AnalysisStrategy::parse(&args[i]);
+                    }
+                }
+                "--diagnostics-format" => {
+                    i += 1;
+                    if i < args.len() {
+                        config.output_config.diagnostics_format = args[i].to_lowercase();
+                    }
+                }
+                "--complexity-threshold" => {
+                    i += 1;
+                    if i < args.len() {
+                        if let Ok(threshold) = args[i].parse() {
+                            config.output_config.complexity_threshold = threshold;
+                        }
+                    }
+                }
+                "--comment-ratio-threshold" => {
+                    i += 1;
+                    if i < args.len() {
+                        if let Ok(threshold) = args[i].parse() {
+                            config.output_config.comment_ratio_threshold = threshold;
+                        }
+                    }
+                }
+                "--files-format" => {
+                    i += 1;
+                    if i < args.len() {
+                        config.output_config.files_format = args[i].to_lowercase();
+                    }
+                }
+                "--max-depth" => {
+                    i += 1;
+                    if i < args.len() {
+                        if let Ok(depth) = args[i].parse() {
+                            config.output_config.files_max_depth = Some(depth);
+                        }
+                    }
+                }
+                "--sort-by" => {
+                    i += 1;
+                    if i < args.len() {
+                        config.output_config.files_sort_by = args[i].to_lowercase();
+                    }
+                }
+                "--prune-below" => {
+                    i += 1;
+                    if i < args.len() {
+                        if let Ok(threshold) = args[i].parse() {
+                            config.output_config.files_prune_below = Some(threshold);
+                        }
+                    }
+                }
+                "--threads" => {
+                    i += 1;
+                    if i < args.len() {
+                        if let Ok(count) = args[i].parse::<usize>() {
+                            config.thread_count = (count > 0).then_some(count);
+                        }
+                    }
+                }
+                "--log-dir" => {
+                    i += 1;
+                    if i < args.len() {
+                        config.log_dir = Some(PathBuf::from(&args[i]));
+                    }
+                }
+                "--log-max-bytes" => {
+                    i += 1;
+                    if i < args.len() {
+                        if let Ok(bytes) = args[i].parse() {
+                            config.log_max_bytes = Some(bytes);
+                        }
+                    }
+                }
+                "--log-max-files" => {
+                    i += 1;
+                    if i < args.len() {
+                        if let Ok(count) = args[i].parse() {
+                            config.log_max_files = Some(count);
+                        }
+                    }
+                }
+                "--json-format" => {
+                    i += 1;
+                    if i < args.len() {
+                        config.output_config.json_format = args[i].to_lowercase();
+                    }
+                }
+                "--watch" => {
+                    config.watch = true;
+                }
+                "--watch-delay" => {
+                    i += 1;
+                    if i < args.len() {
+                        if let Ok(ms) = args[i].parse() {
+                            config.watch_delay_ms = ms;
+                        }
+                    }
+                }
+                "--clear" => {
+                    config.clear_on_watch = true;
+                }
+                "--type" => {
+                    i += 1;
+                    if i < args.len() {
+                        config.type_only = args[i].split(',').map(|s| s.trim().to_string()).collect();
+                    }
+                }
+                "--type-not" => {
+                    i += 1;
+                    if i < args.len() {
+                        config.type_not = args[i].split(',').map(|s| s.trim().to_string()).collect();
+                    }
+                }
+                "--type-add" => {
+                    i += 1;
+                    if i < args.len() {
+                        if let Some((name, glob)) = args[i].split_once(':') {
+                            config
+                                .type_add
+                                .push((name.trim().to_string(), glob.trim().to_string()));
+                        } else {
+                            println!(
+                                "Warning: --type-add expects 'name:glob', got: {}",
+                                args[i]
+                            );
+                        }
+                    }
+                }
                 arg if arg.starts_with("--output=") => {
                     let parts: Vec<&str> = arg.splitn(2, '=').collect();
                     if parts.len() == 2 {
@@ -213,6 +674,16 @@ impl Config {
                         }
                     }
                 }
+                arg if arg.starts_with("--") => {
+                    if let Some(suggestion) = suggest_closest(arg, &VALID_FLAGS) {
+                        println!(
+                            "Warning: Unknown flag: {} - did you mean '{}'?",
+                            arg, suggestion
+                        );
+                    } else {
+                        println!("Warning: Unknown flag: {}", arg);
+                    }
+                }
                 _ => {
                     if config.dir_path.as_os_str().is_empty() {
                         config.dir_path = PathBuf::from(&args[i]);
@@ -261,9 +732,38 @@ impl Config {
             config.generated_types.insert(OutputType::Meta);
         }
 
+        // Resolve the single authoritative ignore matcher now that every relevant
+        // flag has been parsed. `--exclude-pattern` and `--override` feed the same
+        // overrides matcher; the latter is just the gitignore-flavored name for it.
+        let override_patterns: HashSet<String> = config
+            .exclude_patterns
+            .union(&config.override_patterns)
+            .cloned()
+            .collect();
+        config.ignore_config = IgnoreConfig::build(
+            &config.dir_path,
+            config.respect_gitignore,
+            config.no_vcs_ignore,
+            config.no_ignore,
+            matches!(
+                config.verbosity,
+                VerbosityLevel::Debug | VerbosityLevel::Trace
+            ),
+            &config.exclude_directories,
+            &override_patterns,
+        );
+
         Ok(config)
     }
 
+    /// Single authoritative ignore decision for `path`, combining `--exclude-dir`,
+    /// `--exclude-pattern`/`--override`, and the layered `.gitignore`/`.ignore`
+    /// matcher resolved once above - so callers no longer need to separately consult
+    /// `exclude_directories`, an overrides matcher, and a gitignore bool themselves.
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        self.ignore_config.is_ignored(path)
+    }
+
     pub fn get_output_path(&self, output_type: &OutputType) -> PathBuf {
         // Check if there's a specific path for this output type
         if let Some(path) = self.output_config.outputs.get(output_type) {
@@ -281,9 +781,28 @@ impl Config {
             OutputType::Workspace => format!("workspace{}.txt", timestamp),
             OutputType::Files => format!("files{}.txt", timestamp),
             OutputType::Tree => format!("tree{}.txt", timestamp),
-            OutputType::Summary => format!("summary{}.txt", timestamp),
+            OutputType::Summary => match self.output_config.summary_format.as_str() {
+                "json" | "json-compact" => format!("summary{}.json", timestamp),
+                _ => format!("summary{}.txt", timestamp),
+            },
             OutputType::Meta => format!("meta{}.json", timestamp),
             OutputType::LLMFormat => format!("llm{}.md", timestamp), // Add this line
+            OutputType::Security => match self.output_config.security_format.as_str() {
+                "github" => format!("security{}.txt", timestamp),
+                "terminal" => format!("security{}.ansi.txt", timestamp),
+                _ => format!("security{}.sarif.json", timestamp),
+            },
+            OutputType::Html => format!("html_report{}", timestamp),
+            OutputType::Dist => format!("workspace{}.tar.gz", timestamp),
+            OutputType::Diagnostics => match self.output_config.diagnostics_format.as_str() {
+                "github" => format!("diagnostics{}.txt", timestamp),
+                _ => format!("diagnostics{}.sarif.json", timestamp),
+            },
+            OutputType::Json => match self.output_config.json_format.as_str() {
+                "ndjson" => format!("report{}.ndjson", timestamp),
+                _ => format!("report{}.json", timestamp),
+            },
+            OutputType::Git => format!("git{}.json", timestamp),
         };
 
         if let Some(dir) = &self.output_config.output_dir {
@@ -292,4 +811,130 @@ impl Config {
             PathBuf::from(filename)
         }
     }
+
+    /// Parses a `workspace-aggregator.toml` at `path`. Every field is optional, so
+    /// a team only needs to commit the handful of settings they actually want to
+    /// share.
+    pub fn from_file(path: &Path) -> io::Result<ConfigFile> {
+        let contents = fs::read_to_string(path)?;
+        toml::from_str(&contents).map_err(|e| Error::new(ErrorKind::InvalidData, e.to_string()))
+    }
+}
+
+/// The subset of `OutputConfig` a `workspace-aggregator.toml` can set.
+#[derive(Debug, Default, Deserialize)]
+pub struct ConfigFileOutput {
+    pub output_dir: Option<PathBuf>,
+    pub security_format: Option<String>,
+    pub summary_format: Option<String>,
+    pub diagnostics_format: Option<String>,
+    pub complexity_threshold: Option<f64>,
+    pub comment_ratio_threshold: Option<f64>,
+    pub files_format: Option<String>,
+    pub files_max_depth: Option<usize>,
+    pub files_sort_by: Option<String>,
+    pub files_prune_below: Option<f64>,
+    pub json_format: Option<String>,
+}
+
+impl ConfigFileOutput {
+    fn apply_to(self, output_config: &mut OutputConfig) {
+        if let Some(v) = self.output_dir {
+            output_config.output_dir = Some(v);
+        }
+        if let Some(v) = self.security_format {
+            output_config.security_format = v.to_lowercase();
+        }
+        if let Some(v) = self.summary_format {
+            output_config.summary_format = v.to_lowercase();
+        }
+        if let Some(v) = self.diagnostics_format {
+            output_config.diagnostics_format = v.to_lowercase();
+        }
+        if let Some(v) = self.complexity_threshold {
+            output_config.complexity_threshold = v;
+        }
+        if let Some(v) = self.comment_ratio_threshold {
+            output_config.comment_ratio_threshold = v;
+        }
+        if let Some(v) = self.files_format {
+            output_config.files_format = v.to_lowercase();
+        }
+        if let Some(v) = self.files_max_depth {
+            output_config.files_max_depth = Some(v);
+        }
+        if let Some(v) = self.files_sort_by {
+            output_config.files_sort_by = v.to_lowercase();
+        }
+        if let Some(v) = self.files_prune_below {
+            output_config.files_prune_below = Some(v);
+        }
+        if let Some(v) = self.json_format {
+            output_config.json_format = v.to_lowercase();
+        }
+    }
+}
+
+/// A parsed `workspace-aggregator.toml`. Every field is optional and, when
+/// present, overrides `Config::new`'s built-in default - a CLI flag then
+/// overrides that in turn, since the CLI loop runs after `apply_to`.
+#[derive(Debug, Default, Deserialize)]
+pub struct ConfigFile {
+    pub exclude_extensions: Option<Vec<String>>,
+    pub exclude_directories: Option<Vec<String>>,
+    pub exclude_patterns: Option<Vec<String>>,
+    pub generate: Option<Vec<String>>,
+    pub verbosity: Option<String>,
+    pub output: Option<ConfigFileOutput>,
+}
+
+impl ConfigFile {
+    fn apply_to(self, config: &mut Config) {
+        if let Some(v) = self.exclude_extensions {
+            config.exclude_extensions = v.into_iter().map(|s| s.to_lowercase()).collect();
+        }
+        if let Some(v) = self.exclude_directories {
+            config.exclude_directories = v.into_iter().collect();
+        }
+        if let Some(v) = self.exclude_patterns {
+            config.exclude_patterns = v.into_iter().collect();
+        }
+        if let Some(v) = self.generate {
+            for typ in &v {
+                if let Some(output_type) = parse_output_type(typ) {
+                    config.generated_types.insert(output_type);
+                }
+            }
+        }
+        if let Some(v) = self.verbosity {
+            config.verbosity = parse_verbosity(&v);
+        }
+        if let Some(output) = self.output {
+            output.apply_to(&mut config.output_config);
+        }
+    }
+}
+
+/// Searches `start` (or its parent, if `start` isn't itself a directory) and each
+/// ancestor up to and including `$HOME` for a `workspace-aggregator.toml`, so a
+/// team can commit one at the repo root and have it apply no matter which
+/// subdirectory is actually scanned.
+fn discover_config_file(start: &Path) -> Option<PathBuf> {
+    let home = env::var("HOME").ok().map(PathBuf::from);
+    let mut dir = if start.is_dir() {
+        start.to_path_buf()
+    } else {
+        start.parent()?.to_path_buf()
+    };
+
+    loop {
+        let candidate = dir.join("workspace-aggregator.toml");
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        if home.as_deref() == Some(dir.as_path()) {
+            return None;
+        }
+        dir = dir.parent()?.to_path_buf();
+    }
 }
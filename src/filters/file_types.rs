@@ -0,0 +1,151 @@
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Built-in named file types, modeled on ripgrep's `--type` table: a name maps to one or
+/// more glob patterns matched against the file's path.
+const DEFAULT_TYPES: &[(&str, &[&str])] = &[
+    ("rust", &["*.rs"]),
+    ("python", &["*.py"]),
+    (
+        "web",
+        &[
+            "*.html", "*.htm", "*.css", "*.scss", "*.sass", "*.less", "*.js", "*.jsx", "*.ts",
+            "*.tsx",
+        ],
+    ),
+    (
+        "config",
+        &[
+            "*.toml",
+            "*.yaml",
+            "*.yml",
+            "*.json",
+            "*.ini",
+            "*.conf",
+            "*.config",
+            "*.properties",
+            "*.props",
+            "*.env",
+        ],
+    ),
+    (
+        "docs",
+        &["*.md", "*.markdown", "*.rst", "*.asciidoc", "*.adoc", "*.txt"],
+    ),
+    ("c", &["*.c", "*.h"]),
+    ("cpp", &["*.cpp", "*.hpp"]),
+    ("java", &["*.java"]),
+    ("go", &["*.go"]),
+    ("php", &["*.php"]),
+    ("ruby", &["*.rb"]),
+    ("swift", &["*.swift"]),
+    ("kotlin", &["*.kt"]),
+    ("scala", &["*.scala"]),
+    ("csharp", &["*.cs"]),
+    (
+        "shell",
+        &["*.sh", "*.bash", "*.zsh", "*.fish", "*.ps1", "*.bat", "*.cmd"],
+    ),
+    ("data", &["*.xml", "*.sql", "*.graphql", "*.proto", "*.svg"]),
+];
+
+/// Maps named file types (e.g. `rust`, `web`) to compiled glob sets, so `--type`/
+/// `--type-not` can select files by category instead of a single flat extension list.
+/// Replaces the old hard-coded `vec!` of extensions in `should_process_file`.
+pub struct FileTypeRegistry {
+    types: HashMap<String, GlobSet>,
+    /// Union of every known pattern, so "is this extension supported at all" stays a
+    /// single glob-set lookup rather than iterating every named type.
+    known: GlobSet,
+}
+
+impl Default for FileTypeRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileTypeRegistry {
+    pub fn new() -> Self {
+        Self::with_overrides(&[])
+    }
+
+    /// `overrides` are `(name, glob)` pairs from `--type-add name:glob`, appended to (or
+    /// defining a new) named type alongside the built-in table.
+    pub fn with_overrides(overrides: &[(String, String)]) -> Self {
+        let mut patterns: HashMap<String, Vec<String>> = HashMap::new();
+        for (name, globs) in DEFAULT_TYPES {
+            patterns
+                .entry((*name).to_string())
+                .or_default()
+                .extend(globs.iter().map(|g| g.to_string()));
+        }
+        for (name, glob) in overrides {
+            patterns.entry(name.clone()).or_default().push(glob.clone());
+        }
+
+        let mut types = HashMap::new();
+        let mut known_builder = GlobSetBuilder::new();
+
+        for (name, globs) in &patterns {
+            let mut builder = GlobSetBuilder::new();
+            for pattern in globs {
+                if let Ok(glob) = Glob::new(pattern) {
+                    builder.add(glob.clone());
+                    known_builder.add(glob);
+                }
+            }
+            if let Ok(set) = builder.build() {
+                types.insert(name.clone(), set);
+            }
+        }
+
+        Self {
+            types,
+            known: known_builder
+                .build()
+                .unwrap_or_else(|_| GlobSetBuilder::new().build().expect("empty glob set")),
+        }
+    }
+
+    /// Names of every built-in or user-defined type this path matches.
+    pub fn types_for(&self, path: &Path) -> Vec<&str> {
+        self.types
+            .iter()
+            .filter(|(_, set)| set.is_match(path))
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    /// Whether `path` matches any known type at all, mirroring the old "is this a
+    /// supported extension" check.
+    pub fn is_known(&self, path: &Path) -> bool {
+        self.known.is_match(path)
+    }
+}
+
+/// Selects which named types `should_process_file` accepts.
+#[derive(Clone, Default)]
+pub enum TypeFilter {
+    #[default]
+    None,
+    /// Only files matching one of these named types are processed.
+    Only(Vec<String>),
+    /// Files matching any of these named types are skipped.
+    Exclude(Vec<String>),
+}
+
+impl TypeFilter {
+    /// Builds a filter from `--type`/`--type-not` values; `--type` takes precedence if
+    /// both are somehow set, since a whitelist is a stronger statement of intent.
+    pub fn new(only: &[String], exclude: &[String]) -> Self {
+        if !only.is_empty() {
+            TypeFilter::Only(only.to_vec())
+        } else if !exclude.is_empty() {
+            TypeFilter::Exclude(exclude.to_vec())
+        } else {
+            TypeFilter::None
+        }
+    }
+}
@@ -1,68 +1,252 @@
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::process::Command;
 
+/// Names of the dedicated, tool-specific ignore files. Unlike `.gitignore` these are
+/// loaded regardless of `--respect-gitignore`/`--no-vcs-ignore`, since they're a
+/// user-curated exclude list independent of the VCS, and are only skipped by
+/// `--no-ignore`.
+const DEDICATED_IGNORE_FILES: [&str; 2] = [".ignore", ".agregatorignore"];
+
+/// Evaluates nested ignore files the way `git` does: each directory under the scanned
+/// root gets its own compiled matcher combining whichever `.gitignore`/`.ignore`/
+/// `.agregatorignore` files it contains (cached so a file is parsed once), and a lookup
+/// walks from the most specific (deepest) directory up to the scanned root, stopping at
+/// the first file whose rules produce an `Ignore` or `Whitelist` match. That lets a
+/// deeper `!foo` whitelist override a parent directory's `foo` ignore, and keeps
+/// unrelated sibling subtrees from leaking rules into each other.
+#[derive(Clone, Default)]
 pub struct GitignoreFilter {
-    gitignore: Option<Gitignore>,
+    root: PathBuf,
+    matchers: HashMap<PathBuf, Gitignore>,
+    /// Rules from git's `core.excludesFile`, which apply workspace-wide rather than
+    /// being scoped to the directory holding the file that declared them.
+    global: Option<Gitignore>,
     verbose: bool,
 }
 
 impl GitignoreFilter {
-    pub fn new(dir: &Path, respect_gitignore: bool, verbose: bool) -> Self {
-        let gitignore = if respect_gitignore {
-            let gitignore_path = dir.join(".gitignore");
-            if gitignore_path.exists() {
-                let mut builder = GitignoreBuilder::new(dir);
+    pub fn new(
+        dir: &Path,
+        respect_gitignore: bool,
+        no_vcs_ignore: bool,
+        no_ignore: bool,
+        verbose: bool,
+    ) -> Self {
+        Self::with_excluded_directories(
+            dir,
+            respect_gitignore,
+            no_vcs_ignore,
+            no_ignore,
+            verbose,
+            &HashSet::new(),
+        )
+    }
 
-                // The add method returns Option<Error>
-                if let Some(err) = builder.add(&gitignore_path) {
-                    if verbose {
-                        println!("⚠️  Failed to add .gitignore: {}", err);
-                    }
-                    None
-                } else {
-                    // Now try to build
-                    match builder.build() {
-                        Ok(gitignore) => {
-                            if verbose {
-                                println!("📝 Using .gitignore patterns from: {}", dir.display());
-                            }
-                            Some(gitignore)
+    /// Like `new`, but skips descending into directories named in `exclude_directories`
+    /// while gathering ignore files, so a user-excluded directory tree (e.g. a huge
+    /// `target` or `node_modules`) isn't walked twice - once here, once by the real
+    /// scan - just to check it for `.gitignore` files it can't contribute rules from
+    /// anyway, since nothing under it is ever processed.
+    pub fn with_excluded_directories(
+        dir: &Path,
+        respect_gitignore: bool,
+        no_vcs_ignore: bool,
+        no_ignore: bool,
+        verbose: bool,
+        exclude_directories: &HashSet<String>,
+    ) -> Self {
+        let mut matchers = HashMap::new();
+
+        if !no_ignore {
+            let mut file_names: Vec<&str> = Vec::new();
+            if respect_gitignore && !no_vcs_ignore {
+                file_names.push(".gitignore");
+            }
+            file_names.extend(DEDICATED_IGNORE_FILES);
+
+            for (owning_dir, files) in find_ignore_files(dir, &file_names, exclude_directories) {
+                // Rooting each builder at the files' own directory (rather than the
+                // scan root) keeps anchored patterns like `/foo` scoped to where the
+                // ignore file actually lives.
+                let mut builder = GitignoreBuilder::new(&owning_dir);
+                let mut added = 0;
+
+                for file in &files {
+                    if let Some(err) = builder.add(file) {
+                        if verbose {
+                            println!("⚠️  Failed to add {}: {}", file.display(), err);
                         }
-                        Err(e) => {
-                            if verbose {
-                                println!("⚠️  Failed to build .gitignore: {}", e);
-                            }
-                            None
+                    } else {
+                        added += 1;
+                    }
+                }
+
+                if added == 0 {
+                    continue;
+                }
+
+                match builder.build() {
+                    Ok(gitignore) => {
+                        matchers.insert(owning_dir, gitignore);
+                    }
+                    Err(e) => {
+                        if verbose {
+                            println!(
+                                "⚠️  Failed to build ignore matcher for {}: {}",
+                                owning_dir.display(),
+                                e
+                            );
                         }
                     }
                 }
-            } else {
-                if verbose {
-                    println!("ℹ️  No .gitignore found in: {}", dir.display());
+            }
+
+            if verbose {
+                if matchers.is_empty() {
+                    println!("ℹ️  No ignore files found under: {}", dir.display());
+                } else {
+                    println!(
+                        "📝 Using ignore rules from {} director{} under: {}",
+                        matchers.len(),
+                        if matchers.len() == 1 { "y" } else { "ies" },
+                        dir.display()
+                    );
                 }
-                None
             }
-        } else {
-            None
-        };
+        }
 
-        Self { gitignore, verbose }
+        let global = (respect_gitignore && !no_vcs_ignore)
+            .then(|| global_excludes_file(dir))
+            .flatten()
+            .and_then(|path| {
+                let mut builder = GitignoreBuilder::new(dir);
+                match builder.add(&path) {
+                    Some(err) => {
+                        if verbose {
+                            println!("⚠️  Failed to add core.excludesFile {}: {}", path.display(), err);
+                        }
+                        None
+                    }
+                    None => builder.build().ok(),
+                }
+            });
+
+        Self {
+            root: dir.to_path_buf(),
+            matchers,
+            global,
+            verbose,
+        }
     }
 
     pub fn is_ignored(&self, path: &Path) -> bool {
-        if let Some(ref gitignore) = self.gitignore {
-            match gitignore.matched(path, false) {
-                ignore::Match::Ignore(_) => {
-                    if self.verbose {
-                        println!("🚫 Ignored by .gitignore: {}", path.display());
+        if self.matchers.is_empty() {
+            return false;
+        }
+
+        let is_dir = path.is_dir();
+        let mut current = if is_dir { Some(path) } else { path.parent() };
+
+        while let Some(dir) = current {
+            if let Some(gitignore) = self.matchers.get(dir) {
+                match gitignore.matched(path, is_dir) {
+                    ignore::Match::Ignore(_) => {
+                        if self.verbose {
+                            println!("🚫 Ignored by {}: {}", dir.display(), path.display());
+                        }
+                        return true;
                     }
-                    true
+                    ignore::Match::Whitelist(_) => return false,
+                    ignore::Match::None => {}
                 }
-                ignore::Match::None => false,
-                ignore::Match::Whitelist(_) => false,
             }
-        } else {
-            false
+
+            if dir == self.root {
+                break;
+            }
+            current = dir.parent();
+        }
+
+        if let Some(global) = &self.global {
+            match global.matched(path, is_dir) {
+                ignore::Match::Ignore(_) => return true,
+                ignore::Match::Whitelist(_) => return false,
+                ignore::Match::None => {}
+            }
+        }
+
+        false
+    }
+}
+
+/// Resolves git's `core.excludesFile` (e.g. `~/.gitignore_global`) the same way `git`
+/// itself does: `git config --get core.excludesFile`, run rooted at `dir` so repo-local
+/// config overrides are honored. Returns `None` if the setting is unset, `git` isn't
+/// available, or the resolved file doesn't exist.
+fn global_excludes_file(dir: &Path) -> Option<PathBuf> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["config", "--get", "core.excludesFile"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let raw = String::from_utf8_lossy(&output.stdout);
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    let expanded = if let Some(rest) = trimmed.strip_prefix("~/") {
+        std::env::var("HOME").ok().map(|home| PathBuf::from(home).join(rest))?
+    } else {
+        PathBuf::from(trimmed)
+    };
+
+    expanded.exists().then_some(expanded)
+}
+
+/// Recursively finds, per directory under `dir`, every file under `dir` whose name is
+/// one of `file_names`, so nested ignore files are honored and not just the ones at the
+/// workspace root.
+fn find_ignore_files(
+    dir: &Path,
+    file_names: &[&str],
+    exclude_directories: &HashSet<String>,
+) -> HashMap<PathBuf, Vec<PathBuf>> {
+    let mut found = HashMap::new();
+    collect_ignore_files(dir, file_names, exclude_directories, &mut found);
+    found
+}
+
+fn collect_ignore_files(
+    dir: &Path,
+    file_names: &[&str],
+    exclude_directories: &HashSet<String>,
+    found: &mut HashMap<PathBuf, Vec<PathBuf>>,
+) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            if name == ".git" || exclude_directories.contains(name) {
+                continue;
+            }
+            collect_ignore_files(&path, file_names, exclude_directories, found);
+        } else if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+            if file_names.contains(&name) {
+                found.entry(dir.to_path_buf()).or_default().push(path);
+            }
         }
     }
 }
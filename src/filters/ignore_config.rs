@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::filters::gitignore::GitignoreFilter;
+use crate::filters::overrides::{OverrideMatch, OverrideMatcher};
+
+/// Single authoritative ignore decision for a scan: the layered `.gitignore`/`.ignore`
+/// matcher plus user-supplied `--exclude-pattern`/`--override` globs and the literal
+/// `--exclude-dir` name list, resolved once in `Config::new`. Traversal code calls
+/// [`IgnoreConfig::is_ignored`] instead of separately consulting `exclude_directories`,
+/// an overrides matcher, and a gitignore bool.
+#[derive(Clone)]
+pub struct IgnoreConfig {
+    gitignore: GitignoreFilter,
+    overrides: OverrideMatcher,
+    exclude_directories: HashSet<String>,
+}
+
+impl IgnoreConfig {
+    #[allow(clippy::too_many_arguments)]
+    pub fn build(
+        dir: &Path,
+        respect_gitignore: bool,
+        no_vcs_ignore: bool,
+        no_ignore: bool,
+        verbose: bool,
+        exclude_directories: &HashSet<String>,
+        override_patterns: &HashSet<String>,
+    ) -> Self {
+        Self {
+            gitignore: GitignoreFilter::with_excluded_directories(
+                dir,
+                respect_gitignore,
+                no_vcs_ignore,
+                no_ignore,
+                verbose,
+                exclude_directories,
+            ),
+            overrides: OverrideMatcher::new(override_patterns),
+            exclude_directories: exclude_directories.clone(),
+        }
+    }
+
+    /// True if `path` should be skipped: a literal `--exclude-dir` name match first,
+    /// then user overrides (rule-order-last-wins, so a `!` pattern can re-include a
+    /// path), then the layered gitignore/.ignore matcher.
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+            if self.exclude_directories.contains(name) {
+                return true;
+            }
+        }
+
+        match self.overrides.matched(path) {
+            OverrideMatch::Ignore => true,
+            OverrideMatch::Whitelist => false,
+            OverrideMatch::None => self.gitignore.is_ignored(path),
+        }
+    }
+}
+
+impl Default for IgnoreConfig {
+    fn default() -> Self {
+        Self {
+            gitignore: GitignoreFilter::default(),
+            overrides: OverrideMatcher::default(),
+            exclude_directories: HashSet::new(),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod file_types;
+pub mod gitignore;
+pub mod ignore_config;
+pub mod overrides;
+pub mod patterns;
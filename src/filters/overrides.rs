@@ -0,0 +1,135 @@
+use globset::{Glob, GlobMatcher};
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Result of testing a path against an [`OverrideMatcher`], mirroring
+/// `ignore::Match`'s three-way outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverrideMatch {
+    Ignore,
+    Whitelist,
+    None,
+}
+
+#[derive(Clone)]
+struct Rule {
+    matcher: GlobMatcher,
+    /// A `!`-prefixed pattern re-includes a path an earlier rule excluded.
+    whitelist: bool,
+}
+
+/// Default ignore rules, expressed as globs rather than the old substring checks. Each
+/// name matches itself at any depth and everything beneath it, so `target` still catches
+/// both `target` and `target/debug/foo.o`. `**/.*` and `**/.*/**` replace the old blanket
+/// "any dotfile component" check as just another (overridable) default rule.
+const DEFAULT_IGNORE_NAMES: &[&str] = &[
+    // Virtual Environments
+    ".venv",
+    "venv",
+    "env",
+    "virtualenv",
+    // Build and Cache
+    "target",
+    "dist",
+    "build",
+    "__pycache__",
+    ".cache",
+    ".next",
+    "tmp",
+    // Dependencies
+    "node_modules",
+    "site-packages",
+    "vendor",
+    "deps",
+    // IDE and Config
+    ".git",
+    ".idea",
+    ".vscode",
+    // Coverage and Tests
+    "coverage",
+    ".pytest_cache",
+    "__tests__",
+    "test-results",
+    // Other
+    ".terraform",
+    ".serverless",
+    ".aws-sam",
+];
+
+/// Configurable replacement for the old substring-based `should_ignore`: a compiled,
+/// ordered list of glob rules (defaults plus user-supplied `--exclude-pattern` globs)
+/// evaluated rule-order-last-wins, so a later `!.github/` can re-include a path an
+/// earlier default or user rule excluded.
+#[derive(Clone)]
+pub struct OverrideMatcher {
+    rules: Vec<Rule>,
+}
+
+impl Default for OverrideMatcher {
+    fn default() -> Self {
+        Self::new(&HashSet::new())
+    }
+}
+
+impl OverrideMatcher {
+    pub fn new(user_patterns: &HashSet<String>) -> Self {
+        let mut rules = Vec::new();
+
+        for name in DEFAULT_IGNORE_NAMES {
+            push_name_rules(&mut rules, name);
+        }
+        push_pattern_rule(&mut rules, ".*");
+        push_pattern_rule(&mut rules, ".*/**");
+        push_pattern_rule(&mut rules, ".DS_Store");
+        push_pattern_rule(&mut rules, ".env");
+        push_pattern_rule(&mut rules, ".coverage");
+
+        for pattern in user_patterns {
+            push_pattern_rule(&mut rules, pattern);
+        }
+
+        Self { rules }
+    }
+
+    pub fn matched(&self, path: &Path) -> OverrideMatch {
+        let mut result = OverrideMatch::None;
+        for rule in &self.rules {
+            if rule.matcher.is_match(path) {
+                result = if rule.whitelist {
+                    OverrideMatch::Whitelist
+                } else {
+                    OverrideMatch::Ignore
+                };
+            }
+        }
+        result
+    }
+}
+
+fn push_name_rules(rules: &mut Vec<Rule>, name: &str) {
+    push_pattern_rule(rules, name);
+    push_pattern_rule(rules, &format!("{}/**", name));
+}
+
+fn push_pattern_rule(rules: &mut Vec<Rule>, pattern: &str) {
+    let (whitelist, raw) = match pattern.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => (false, pattern),
+    };
+    let raw = raw.strip_suffix('/').unwrap_or(raw);
+
+    // Anchoring every rule under `**/` lets a bare name match at any depth, matching
+    // the old substring behavior rather than requiring a leading `/` for that.
+    let anchored = if raw.starts_with("**/") {
+        raw.to_string()
+    } else {
+        format!("**/{}", raw)
+    };
+
+    if let Ok(glob) = Glob::new(&anchored) {
+        rules.push(Rule {
+            matcher: glob.compile_matcher(),
+            whitelist,
+        });
+    }
+}
@@ -1,143 +1,72 @@
+use crate::filters::file_types::{FileTypeRegistry, TypeFilter};
 use std::collections::HashSet;
+use std::fmt;
 use std::path::Path;
 
-pub fn should_ignore(path: &Path) -> bool {
-    let ignore_patterns = vec![
-        // Virtual Environments
-        ".venv",
-        "venv",
-        "env",
-        "virtualenv",
-        // Build and Cache
-        "target",
-        "dist",
-        "build",
-        "__pycache__",
-        ".cache",
-        ".next",
-        "tmp",
-        // Dependencies
-        "node_modules",
-        "site-packages",
-        "vendor",
-        "deps",
-        // IDE and Config
-        ".git",
-        ".idea",
-        ".vscode",
-        ".env",
-        ".DS_Store",
-        // Coverage and Tests
-        "coverage",
-        ".coverage",
-        ".pytest_cache",
-        "__tests__",
-        "test-results",
-        // Other
-        ".terraform",
-        ".serverless",
-        ".aws-sam",
-    ];
-
-    let path_str = path.to_string_lossy();
-
-    if path_str
-        .split('/')
-        .any(|part| part.starts_with('.') && part != "." && part != "..")
-    {
-        return true;
-    }
+/// Why `should_process_file` rejected (or accepted) a path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkipReason {
+    Accepted,
+    NoExtension,
+    UnsupportedExtension(String),
+    ExcludedExtension(String),
+    NotInSelectedTypes,
+    ExcludedType(String),
+}
 
-    for pattern in &ignore_patterns {
-        if path_str.contains(&format!("/{}/", pattern))
-            || path_str.starts_with(&format!("{}/", pattern))
-            || path_str.ends_with(&format!("/{}", pattern))
-        {
-            return true;
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SkipReason::Accepted => write!(f, "Accepted"),
+            SkipReason::NoExtension => write!(f, "No extension"),
+            SkipReason::UnsupportedExtension(ext) => write!(f, "Unsupported extension: {}", ext),
+            SkipReason::ExcludedExtension(ext) => write!(f, "Excluded extension: {}", ext),
+            SkipReason::NotInSelectedTypes => write!(f, "Not in --type selection"),
+            SkipReason::ExcludedType(name) => write!(f, "Excluded by --type-not: {}", name),
         }
     }
-
-    false
 }
 
-pub fn should_process_file(path: &Path, exclude_extensions: &HashSet<String>) -> (bool, String) {
-    let extensions = vec![
-        // Programming Languages
-        "txt",
-        "md",
-        "rs",
-        "py",
-        "js",
-        "jsx",
-        "ts",
-        "tsx",
-        "java",
-        "c",
-        "cpp",
-        "h",
-        "hpp",
-        "cs",
-        "go",
-        "php",
-        "rb",
-        "swift",
-        "kt",
-        "scala",
-        // Web
-        "html",
-        "htm",
-        "css",
-        "scss",
-        "sass",
-        "less",
-        "svg",
-        // Config & Data
-        "json",
-        "yaml",
-        "yml",
-        "xml",
-        "toml",
-        "ini",
-        "conf",
-        "config",
-        "properties",
-        "props",
-        "env",
-        // Documentation
-        "markdown",
-        "rst",
-        "asciidoc",
-        "adoc",
-        // Scripts
-        "sh",
-        "bash",
-        "zsh",
-        "fish",
-        "ps1",
-        "bat",
-        "cmd",
-        // Other
-        "sql",
-        "graphql",
-        "proto",
-    ];
+/// Thin wrapper over `FileTypeRegistry`/`TypeFilter`: extension support and exclusion
+/// are now glob-based rather than a fixed `vec!` of extensions, so files like
+/// `Makefile` or `*.rs.in` can be matched via a user-defined type.
+pub fn should_process_file(
+    path: &Path,
+    exclude_extensions: &HashSet<String>,
+    registry: &FileTypeRegistry,
+    type_filter: &TypeFilter,
+) -> (bool, SkipReason) {
+    let Some(ext_lower) = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+    else {
+        return (false, SkipReason::NoExtension);
+    };
 
-    if let Some(ext) = path.extension() {
-        if let Some(ext_str) = ext.to_str() {
-            let ext_lower = ext_str.to_lowercase();
-            let is_valid_ext = extensions.contains(&ext_lower.as_str());
-            let is_not_excluded = !exclude_extensions.contains(&ext_lower);
+    if !registry.is_known(path) {
+        return (false, SkipReason::UnsupportedExtension(ext_lower));
+    }
 
-            let reason = if !is_valid_ext {
-                format!("Unsupported extension: {}", ext_lower)
-            } else if !is_not_excluded {
-                format!("Excluded extension: {}", ext_lower)
-            } else {
-                String::new()
-            };
+    if exclude_extensions.contains(&ext_lower) {
+        return (false, SkipReason::ExcludedExtension(ext_lower));
+    }
 
-            return (is_valid_ext && is_not_excluded, reason);
+    match type_filter {
+        TypeFilter::Only(names) => {
+            let file_types = registry.types_for(path);
+            if !names.iter().any(|n| file_types.contains(&n.as_str())) {
+                return (false, SkipReason::NotInSelectedTypes);
+            }
         }
+        TypeFilter::Exclude(names) => {
+            let file_types = registry.types_for(path);
+            if let Some(blocked) = names.iter().find(|n| file_types.contains(&n.as_str())) {
+                return (false, SkipReason::ExcludedType(blocked.clone()));
+            }
+        }
+        TypeFilter::None => {}
     }
-    (false, "No extension".to_string())
+
+    (true, SkipReason::Accepted)
 }
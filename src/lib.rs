@@ -7,7 +7,7 @@ pub mod version;
 
 // Re-export commonly used items
 pub use config::Config;
-pub use processor::analysis::{CodeIndex, DependencyAnalyzer, MetricsAnalyzer};
+pub use processor::analysis::{CodeIndex, DependencyAnalyzer, MetricsAnalyzer, SymbolKind, SymbolReference};
 pub use processor::types::*;
 pub use processor::FileProcessor;
 pub use version::*;
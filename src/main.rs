@@ -1,6 +1,8 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::{self, Command};
 use workspace_aggregator::cli::help::{print_help, print_short_help, print_version};
+use workspace_aggregator::cli::serve;
+use workspace_aggregator::cli::watch;
 use workspace_aggregator::version;
 use workspace_aggregator::{Config, FileProcessor};
 
@@ -63,6 +65,36 @@ fn update_self() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+fn parse_serve_args(args: &[String]) -> (PathBuf, PathBuf, u16) {
+    let mut dir = PathBuf::from(".");
+    let mut output_dir = PathBuf::from("docs/html_report");
+    let mut port = serve::DEFAULT_PORT;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--port" => {
+                i += 1;
+                if let Some(value) = args.get(i) {
+                    if let Ok(parsed) = value.parse() {
+                        port = parsed;
+                    }
+                }
+            }
+            "--output-dir" => {
+                i += 1;
+                if let Some(value) = args.get(i) {
+                    output_dir = PathBuf::from(value);
+                }
+            }
+            arg => dir = PathBuf::from(arg),
+        }
+        i += 1;
+    }
+
+    (dir, output_dir, port)
+}
+
 fn main() {
     let args: Vec<String> = std::env::args().collect();
 
@@ -86,6 +118,14 @@ fn main() {
                     process::exit(1);
                 }
             },
+            "serve" => {
+                let (dir, output_dir, port) = parse_serve_args(&args[2..]);
+                if let Err(e) = serve::run(dir, output_dir, port) {
+                    eprintln!("❌ Serve failed: {}", e);
+                    process::exit(1);
+                }
+                process::exit(0);
+            }
             _ => {}
         }
     }
@@ -105,6 +145,14 @@ fn main() {
         }
     };
 
+    if config.watch {
+        if let Err(err) = watch::run(config) {
+            eprintln!("❌ Error during watch: {}", err);
+            process::exit(1);
+        }
+        return;
+    }
+
     // Create and run processor
     let mut processor = FileProcessor::new(config);
 
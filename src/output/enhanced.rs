@@ -1,91 +0,0 @@
-use std::path::{Path, PathBuf};
-use std::fs;
-use std::io;
-use serde_json;
-use serde_yaml;
-
-pub struct EnhancedOutputGenerator {
-    base_dir: PathBuf,
-}
-
-impl EnhancedOutputGenerator {
-    pub fn new(base_dir: PathBuf) -> Self {
-        Self { base_dir }
-    }
-
-    pub fn generate(&self, stats: &EnhancedFileStats) -> io::Result<()> {
-        // Create output directory structure
-        let output_dir = self.base_dir.join("analysis");
-        fs::create_dir_all(&output_dir)?;
-
-        // Generate project metadata
-        self.generate_project_meta(&output_dir, stats)?;
-
-        // Generate language statistics
-        self.generate_language_stats(&output_dir, stats)?;
-
-        // Generate dependency information
-        self.generate_dependencies(&output_dir, stats)?;
-
-        // Generate code symbols
-        self.generate_code_symbols(&output_dir, stats)?;
-
-        // Generate metrics
-        self.generate_metrics(&output_dir, stats)?;
-
-        // Generate indexes
-        self.generate_indexes(&output_dir, stats)?;
-
-        Ok(())
-    }
-
-    fn generate_project_meta(&self, output_dir: &Path, stats: &EnhancedFileStats) -> io::Result<()> {
-        let meta = ProjectMeta {
-            name: "workspace-aggregator".to_string(),
-            timestamp: Utc::now(),
-            version: env!("CARGO_PKG_VERSION").to_string(),
-            stats: ProjectStats {
-                total_files: stats.file_statistics.len(),
-                total_size: stats.total_size,
-                last_update: Utc::now(),
-            },
-            incremental: IncrementalStats {
-                last_scan: Utc::now(),
-                changed_files: stats.changed_files.len(),
-                added_files: stats.added_files.len(),
-                removed_files: stats.removed_files.len(),
-            },
-        };
-
-        let yaml = serde_yaml::to_string(&meta)?;
-        fs::write(output_dir.join("project_meta.yaml"), yaml)?;
-        Ok(())
-    }
-
-    fn generate_language_stats(&self, output_dir: &Path, stats: &EnhancedFileStats) -> io::Result<()> {
-        let mut language_stats = HashMap::new();
-
-        for (lang, stats) in &stats.language_stats {
-            language_stats.insert(lang.clone(), LanguageStats {
-                files: stats.files,
-                stats: CodeStats {
-                    total_lines: stats.lines,
-                    code_lines: stats.code_lines,
-                    comment_lines: stats.comment_lines,
-                    blank_lines: stats.blank_lines,
-                },
-                quality: QualityMetrics {
-                    documentation_coverage: calculate_doc_coverage(stats),
-                    test_coverage: calculate_test_coverage(stats),
-                    complexity_score: calculate_complexity_score(stats),
-                },
-            });
-        }
-
-        let yaml = serde_yaml::to_string(&language_stats)?;
-        fs::write(output_dir.join("language_stats.yaml"), yaml)?;
-        Ok(())
-    }
-
-    // Add other generation methods...
-}
@@ -7,9 +7,90 @@ use std::path::{Path, PathBuf};
 use crate::config::VerbosityLevel;
 use crate::processor::types::EnhancedFileStats;
 
+/// Metric used to sort sibling nodes and to test against `prune_below` in tree mode.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SortMetric {
+    Name,
+    Size,
+    Lines,
+    Complexity,
+}
+
+impl SortMetric {
+    fn parse(s: &str) -> Self {
+        match s {
+            "size" => SortMetric::Size,
+            "lines" => SortMetric::Lines,
+            "complexity" => SortMetric::Complexity,
+            _ => SortMetric::Name,
+        }
+    }
+}
+
+/// Rolled-up totals for a directory node: sums over every file in its subtree, plus
+/// the mean cyclomatic complexity (sum / file count) for an at-a-glance hotspot score.
+#[derive(Default, Clone)]
+struct NodeMetrics {
+    file_count: usize,
+    total_lines: usize,
+    total_size: u64,
+    total_complexity: f64,
+}
+
+impl NodeMetrics {
+    fn mean_complexity(&self) -> f64 {
+        if self.file_count == 0 {
+            0.0
+        } else {
+            self.total_complexity / self.file_count as f64
+        }
+    }
+
+    fn merge(&mut self, other: &NodeMetrics) {
+        self.file_count += other.file_count;
+        self.total_lines += other.total_lines;
+        self.total_size += other.total_size;
+        self.total_complexity += other.total_complexity;
+    }
+
+    fn sort_key(&self, metric: SortMetric) -> f64 {
+        match metric {
+            SortMetric::Name => 0.0,
+            SortMetric::Size => self.total_size as f64,
+            SortMetric::Lines => self.total_lines as f64,
+            SortMetric::Complexity => self.mean_complexity(),
+        }
+    }
+}
+
+enum TreeNode {
+    File { name: String, metrics: NodeMetrics },
+    Dir { name: String, metrics: NodeMetrics, children: Vec<TreeNode> },
+}
+
+impl TreeNode {
+    fn metrics(&self) -> &NodeMetrics {
+        match self {
+            TreeNode::File { metrics, .. } => metrics,
+            TreeNode::Dir { metrics, .. } => metrics,
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            TreeNode::File { name, .. } => name,
+            TreeNode::Dir { name, .. } => name,
+        }
+    }
+}
+
 pub struct FilesOutput {
     base_path: PathBuf,
     verbose_level: VerbosityLevel,
+    tree_mode: bool,
+    max_depth: Option<usize>,
+    sort_by: String,
+    prune_below: Option<f64>,
 }
 
 impl FilesOutput {
@@ -17,9 +98,30 @@ impl FilesOutput {
         Self {
             base_path,
             verbose_level,
+            tree_mode: false,
+            max_depth: None,
+            sort_by: "name".to_string(),
+            prune_below: None,
         }
     }
 
+    /// Switches `generate` into directory-tree mode: siblings are sorted by `sort_by`
+    /// ("size", "lines", "complexity", or "name"), nodes deeper than `max_depth` are
+    /// collapsed into their parent, and directories whose rolled-up `sort_by` metric
+    /// falls below `prune_below` are dropped from the render.
+    pub fn with_tree_mode(
+        mut self,
+        max_depth: Option<usize>,
+        sort_by: String,
+        prune_below: Option<f64>,
+    ) -> Self {
+        self.tree_mode = true;
+        self.max_depth = max_depth;
+        self.sort_by = sort_by;
+        self.prune_below = prune_below;
+        self
+    }
+
     pub fn generate(&self, output_path: &Path, stats: &EnhancedFileStats) -> io::Result<()> {
         self.log(
             VerbosityLevel::Info,
@@ -32,34 +134,38 @@ impl FilesOutput {
         // Write header
         self.write_header(&mut writer, stats)?;
 
-        // Group files by type
-        let mut files_by_type: HashMap<String, Vec<&Path>> = HashMap::new();
-
-        for path in stats.file_statistics.keys() {
-            let ext = path
-                .extension()
-                .and_then(|e| e.to_str())
-                .unwrap_or("unknown")
-                .to_string();
-
-            self.log(
-                VerbosityLevel::Debug,
-                &format!("Processing file: {} (type: {})", path.display(), ext),
-            );
-            files_by_type.entry(ext).or_default().push(path);
-        }
-
-        // Write each group
-        for (ext, files) in files_by_type {
-            self.log(
-                VerbosityLevel::Debug,
-                &format!("Writing {} files group", ext),
-            );
-            writeln!(writer, "\n## {} files", ext.to_uppercase())?;
-
-            for path in files {
-                if let Ok(relative) = path.strip_prefix(&self.base_path) {
-                    writeln!(writer, "{}", relative.display())?;
+        if self.tree_mode {
+            self.write_tree(&mut writer, stats)?;
+        } else {
+            // Group files by type
+            let mut files_by_type: HashMap<String, Vec<&Path>> = HashMap::new();
+
+            for path in stats.file_statistics.keys() {
+                let ext = path
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .unwrap_or("unknown")
+                    .to_string();
+
+                self.log(
+                    VerbosityLevel::Debug,
+                    &format!("Processing file: {} (type: {})", path.display(), ext),
+                );
+                files_by_type.entry(ext).or_default().push(path);
+            }
+
+            // Write each group
+            for (ext, files) in files_by_type {
+                self.log(
+                    VerbosityLevel::Debug,
+                    &format!("Writing {} files group", ext),
+                );
+                writeln!(writer, "\n## {} files", ext.to_uppercase())?;
+
+                for path in files {
+                    if let Ok(relative) = path.strip_prefix(&self.base_path) {
+                        writeln!(writer, "{}", relative.display())?;
+                    }
                 }
             }
         }
@@ -72,6 +178,113 @@ impl FilesOutput {
         Ok(())
     }
 
+    /// Builds the directory tree from `stats.file_statistics`'s paths (rather than
+    /// re-walking the filesystem), rolls up `NodeMetrics` from the leaves upward,
+    /// prunes, sorts, and renders it with the same box-drawing connectors as
+    /// [`crate::output::tree::TreeOutput`].
+    fn write_tree(&self, writer: &mut impl Write, stats: &EnhancedFileStats) -> io::Result<()> {
+        let mut root = TreeNode::Dir {
+            name: self
+                .base_path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| self.base_path.display().to_string()),
+            metrics: NodeMetrics::default(),
+            children: Vec::new(),
+        };
+
+        for (path, file_stats) in &stats.file_statistics {
+            let relative = match path.strip_prefix(&self.base_path) {
+                Ok(relative) => relative,
+                Err(_) => continue,
+            };
+            let metrics = NodeMetrics {
+                file_count: 1,
+                total_lines: file_stats.lines,
+                total_size: file_stats.size,
+                total_complexity: file_stats.complexity.cyclomatic_complexity,
+            };
+            insert_file(&mut root, relative, metrics);
+        }
+
+        roll_up(&mut root);
+
+        let metric = SortMetric::parse(&self.sort_by);
+        if let Some(threshold) = self.prune_below {
+            prune(&mut root, metric, threshold);
+        }
+        sort_children(&mut root, metric);
+
+        writeln!(writer, "## Directory Tree")?;
+        writeln!(writer)?;
+        self.print_node(&root, "", true, 0, writer)?;
+        writeln!(writer)?;
+        Ok(())
+    }
+
+    fn print_node(
+        &self,
+        node: &TreeNode,
+        prefix: &str,
+        is_root: bool,
+        depth: usize,
+        writer: &mut impl Write,
+    ) -> io::Result<()> {
+        if is_root {
+            writeln!(writer, "{}/ {}", node.name(), self.metrics_suffix(node.metrics()))?;
+        }
+
+        if let Some(max_depth) = self.max_depth {
+            if depth >= max_depth {
+                return Ok(());
+            }
+        }
+
+        if let TreeNode::Dir { children, .. } = node {
+            let total = children.len();
+            for (i, child) in children.iter().enumerate() {
+                let is_last = i == total - 1;
+                let branch = if is_last { "└── " } else { "├── " };
+                let next_prefix = format!("{}{}", prefix, if is_last { "    " } else { "│   " });
+
+                match child {
+                    TreeNode::Dir { name, metrics, .. } => {
+                        writeln!(
+                            writer,
+                            "{}{}{}/ {}",
+                            prefix,
+                            branch,
+                            name,
+                            self.metrics_suffix(metrics)
+                        )?;
+                        self.print_node(child, &next_prefix, false, depth + 1, writer)?;
+                    }
+                    TreeNode::File { name, metrics } => {
+                        writeln!(
+                            writer,
+                            "{}{}{} {}",
+                            prefix,
+                            branch,
+                            name,
+                            self.metrics_suffix(metrics)
+                        )?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn metrics_suffix(&self, metrics: &NodeMetrics) -> String {
+        format!(
+            "(files: {}, lines: {}, complexity: {:.1})",
+            metrics.file_count,
+            metrics.total_lines,
+            metrics.mean_complexity()
+        )
+    }
+
     fn write_header(&self, writer: &mut impl Write, stats: &EnhancedFileStats) -> io::Result<()> {
         self.log(VerbosityLevel::Debug, "Writing header");
         writeln!(writer, "# Processed Files List")?;
@@ -108,3 +321,99 @@ impl FilesOutput {
         }
     }
 }
+
+/// Walks `dir`'s children, creating directory nodes as needed, until `relative`'s
+/// final component is reached, then appends a leaf file node with `metrics`.
+fn insert_file(dir: &mut TreeNode, relative: &Path, metrics: NodeMetrics) {
+    let TreeNode::Dir { children, .. } = dir else {
+        return;
+    };
+
+    let mut components = relative.components();
+    let Some(first) = components.next() else {
+        return;
+    };
+    let first_name = first.as_os_str().to_string_lossy().to_string();
+    let rest: PathBuf = components.collect();
+
+    if rest.as_os_str().is_empty() {
+        children.push(TreeNode::File {
+            name: first_name,
+            metrics,
+        });
+        return;
+    }
+
+    let child_idx = children.iter().position(|child| {
+        matches!(child, TreeNode::Dir { name, .. } if *name == first_name)
+    });
+    let child_idx = child_idx.unwrap_or_else(|| {
+        children.push(TreeNode::Dir {
+            name: first_name.clone(),
+            metrics: NodeMetrics::default(),
+            children: Vec::new(),
+        });
+        children.len() - 1
+    });
+
+    insert_file(&mut children[child_idx], &rest, metrics);
+}
+
+/// Sums every leaf's metrics up into its ancestors, post-order, so each directory
+/// node ends up holding the rolled-up totals for its entire subtree.
+fn roll_up(node: &mut TreeNode) -> NodeMetrics {
+    match node {
+        TreeNode::File { metrics, .. } => metrics.clone(),
+        TreeNode::Dir { metrics, children, .. } => {
+            let mut rolled = NodeMetrics::default();
+            for child in children.iter_mut() {
+                rolled.merge(&roll_up(child));
+            }
+            *metrics = rolled.clone();
+            rolled
+        }
+    }
+}
+
+/// Drops directory children whose rolled-up `metric` falls below `threshold`. Files
+/// are left untouched - pruning only collapses low-signal subtrees, not individual
+/// leaves, which a depth/sort choice already surfaces or hides as desired.
+fn prune(node: &mut TreeNode, metric: SortMetric, threshold: f64) {
+    if let TreeNode::Dir { children, .. } = node {
+        children.retain(|child| match child {
+            TreeNode::Dir { metrics, .. } => metrics.sort_key(metric) >= threshold,
+            TreeNode::File { .. } => true,
+        });
+        for child in children.iter_mut() {
+            prune(child, metric, threshold);
+        }
+    }
+}
+
+/// Orders siblings so hotspots float to the top: descending by `metric` when it's a
+/// real measurement, or alphabetically (directories first) for [`SortMetric::Name`].
+fn sort_children(node: &mut TreeNode, metric: SortMetric) {
+    if let TreeNode::Dir { children, .. } = node {
+        if metric == SortMetric::Name {
+            children.sort_by(|a, b| {
+                let a_is_dir = matches!(a, TreeNode::Dir { .. });
+                let b_is_dir = matches!(b, TreeNode::Dir { .. });
+                if a_is_dir == b_is_dir {
+                    a.name().cmp(b.name())
+                } else {
+                    b_is_dir.cmp(&a_is_dir)
+                }
+            });
+        } else {
+            children.sort_by(|a, b| {
+                b.metrics()
+                    .sort_key(metric)
+                    .partial_cmp(&a.metrics().sort_key(metric))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+        }
+        for child in children.iter_mut() {
+            sort_children(child, metric);
+        }
+    }
+}
@@ -1,7 +1,5 @@
 pub mod files;
 pub mod tree;
-pub mod workspace;
 
 pub use files::FilesOutput;
 pub use tree::TreeOutput;
-pub use workspace::WorkspaceOutput;
@@ -3,34 +3,34 @@ use std::fs::File;
 use std::io::{self, BufWriter, Write};
 use std::path::{Path, PathBuf};
 
-use crate::config::VerbosityLevel;
-use crate::filters::gitignore::GitignoreFilter;
-use crate::filters::patterns::should_ignore;
+use crate::config::{Config, VerbosityLevel};
+use crate::processor::analysis::GitStatus;
 
 pub struct TreeOutput {
     base_path: PathBuf,
     verbose_level: VerbosityLevel,
-    gitignore_filter: Option<GitignoreFilter>,
+    // Ignore decisions go through `Config::is_ignored` - the single authoritative
+    // source also used by the main traversal - rather than this type building its own
+    // gitignore/override matchers.
+    config: Config,
     respect_gitignore: bool,
+    git_status: Option<GitStatus>,
 }
 
 impl TreeOutput {
-    pub fn new(base_path: PathBuf, verbose_level: VerbosityLevel, respect_gitignore: bool) -> Self {
-        let gitignore_filter = if respect_gitignore {
-            Some(GitignoreFilter::new(
-                &base_path,
-                true,
-                matches!(verbose_level, VerbosityLevel::Debug | VerbosityLevel::Trace),
-            ))
+    pub fn new(config: &Config) -> Self {
+        let git_status = if config.show_git_status {
+            GitStatus::collect(&config.dir_path)
         } else {
             None
         };
 
         Self {
-            base_path,
-            verbose_level,
-            gitignore_filter,
-            respect_gitignore,
+            base_path: config.dir_path.clone(),
+            verbose_level: config.verbosity.clone(),
+            config: config.clone(),
+            respect_gitignore: config.respect_gitignore,
+            git_status,
         }
     }
 
@@ -56,28 +56,13 @@ impl TreeOutput {
     }
 
     fn should_skip(&self, path: &Path) -> bool {
-        // Check standard ignore patterns
-        if should_ignore(path) {
+        if self.config.is_ignored(path) {
             self.log(
                 VerbosityLevel::Debug,
                 &format!("Skipping ignored path: {}", path.display()),
             );
             return true;
         }
-
-        // Check gitignore if enabled
-        if self.respect_gitignore {
-            if let Some(ref gitignore) = self.gitignore_filter {
-                if gitignore.is_ignored(path) {
-                    self.log(
-                        VerbosityLevel::Debug,
-                        &format!("Skipping gitignored path: {}", path.display()),
-                    );
-                    return true;
-                }
-            }
-        }
-
         false
     }
 
@@ -92,6 +77,9 @@ impl TreeOutput {
         if self.respect_gitignore {
             writeln!(writer, "Note: Respecting .gitignore rules")?;
         }
+        if self.git_status.is_some() {
+            writeln!(writer, "Note: Showing git status (index, worktree)")?;
+        }
         writeln!(writer)?;
         Ok(())
     }
@@ -134,6 +122,8 @@ impl TreeOutput {
             let branch = if is_last { "└── " } else { "├── " };
             let next_prefix = if is_last { "    " } else { "│   " };
 
+            let status_marker = self.status_marker(&path);
+
             if path.is_dir() {
                 self.log(
                     VerbosityLevel::Trace,
@@ -141,9 +131,10 @@ impl TreeOutput {
                 );
                 writeln!(
                     writer,
-                    "{}{}{}/",
+                    "{}{}{}{}/",
                     prefix,
                     branch,
+                    status_marker,
                     path.file_name().unwrap().to_string_lossy()
                 )?;
                 self.print_tree(
@@ -159,9 +150,10 @@ impl TreeOutput {
                 );
                 writeln!(
                     writer,
-                    "{}{}{}",
+                    "{}{}{}{}",
                     prefix,
                     branch,
+                    status_marker,
                     path.file_name().unwrap().to_string_lossy()
                 )?;
             }
@@ -170,6 +162,19 @@ impl TreeOutput {
         Ok(())
     }
 
+    /// Renders `path`'s git status as a `"[XY] "` prefix (index status, worktree
+    /// status, mirroring `git status`'s porcelain columns), or an empty string when
+    /// status display is off or the path is unmodified.
+    fn status_marker(&self, path: &Path) -> String {
+        match &self.git_status {
+            Some(status) => match status.marker_for(path) {
+                Some(code) => format!("[{}] ", code),
+                None => "[  ] ".to_string(),
+            },
+            None => String::new(),
+        }
+    }
+
     fn log(&self, level: VerbosityLevel, message: &str) {
         if self.verbose_level >= level {
             match level {
@@ -182,3 +187,44 @@ impl TreeOutput {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `TreeOutput::new` used to hardcode an empty override set, so
+    /// `--exclude-pattern`/`--override` globs had no effect on `--generate tree` even
+    /// though the same globs were honored everywhere else. Now it goes through
+    /// `Config::is_ignored`, the same authoritative source as the main traversal.
+    #[test]
+    fn exclude_pattern_is_honored() {
+        let test_dir = std::env::temp_dir().join(format!(
+            "workspace_aggregator_tree_test_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&test_dir);
+        std::fs::create_dir_all(&test_dir).unwrap();
+        std::fs::write(test_dir.join("keep.rs"), "fn main() {}").unwrap();
+        std::fs::write(test_dir.join("secret.md"), "# secret").unwrap();
+
+        std::env::set_var(
+            "CARGO_TEST_ARGS",
+            format!(
+                "workspace-aggregator {} --exclude-pattern *.md",
+                test_dir.display()
+            ),
+        );
+        let config = Config::new().expect("config should parse");
+
+        let tree_output = TreeOutput::new(&config);
+
+        let output_path = test_dir.join("tree.txt");
+        tree_output.generate(&output_path).unwrap();
+        let contents = std::fs::read_to_string(&output_path).unwrap();
+
+        assert!(contents.contains("keep.rs"));
+        assert!(!contents.contains("secret.md"));
+
+        let _ = std::fs::remove_dir_all(&test_dir);
+    }
+}
@@ -1,5 +1,8 @@
+use crate::processor::analysis::language::{LanguageDefinition, LanguageDetector};
 use crate::processor::types::CodeComplexity;
 use std::path::Path;
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
 
 pub struct ComplexityAnalyzer {
     pub total_complexity: f64,
@@ -20,47 +23,238 @@ impl ComplexityAnalyzer {
         }
     }
 
-    pub fn analyze_file(&mut self, content: &str, _path: &Path) -> CodeComplexity {
+    /// Scores `content`'s cyclomatic complexity and comment ratio using `language`'s
+    /// comment delimiters and branch-introducing tokens (looked up via `detector`),
+    /// rather than the old hard-coded C-style/English-keyword heuristics. Matching is
+    /// token-boundary aware and skips occurrences inside string/char literals and
+    /// comments, so e.g. a string containing `"if "` no longer inflates the count.
+    pub fn analyze_file(
+        &self,
+        content: &str,
+        _path: &Path,
+        language: &str,
+        detector: &LanguageDetector,
+    ) -> CodeComplexity {
         let mut complexity = CodeComplexity::default();
 
         let lines: Vec<&str> = content.lines().collect();
         complexity.lines_of_code = lines.len();
 
-        let mut branch_points = 0;
-        let mut comment_lines = 0;
+        let default_def = LanguageDefinition {
+            extensions: Vec::new(),
+            line_comments: Vec::new(),
+            block_comments: Vec::new(),
+            nested_block_comments: false,
+            string_delimiters: Vec::new(),
+            branch_tokens: Vec::new(),
+        };
+        let def = detector.definition(language).unwrap_or(&default_def);
+
+        let (branch_points, comment_lines) = count_branches_and_comments(&lines, def);
+
+        complexity.cyclomatic_complexity = 1.0 + branch_points as f64;
+        complexity.comment_ratio = if !lines.is_empty() {
+            comment_lines as f64 / lines.len() as f64
+        } else {
+            0.0
+        };
+
+        // Function spans are only resolvable where we have a real parser (Rust, via
+        // `syn`); other languages fall back to the whole-file number above.
+        if language == "Rust" {
+            complexity.function_complexities = rust_function_complexities(content, def);
+            complexity.function_count = complexity.function_complexities.len();
+        }
+
+        complexity
+    }
+}
+
+/// Parses `content` as a Rust source file and scores cyclomatic complexity
+/// per free function/method using each item's line span, so workspace-wide
+/// complexity metrics can reflect functions rather than one number per file.
+/// Returns an empty vec when `content` doesn't parse as valid Rust.
+fn rust_function_complexities(content: &str, def: &LanguageDefinition) -> Vec<f64> {
+    let Ok(file) = syn::parse_file(content) else {
+        return Vec::new();
+    };
+
+    let lines: Vec<&str> = content.lines().collect();
+    let mut visitor = FunctionSpanVisitor::default();
+    visitor.visit_file(&file);
+
+    visitor
+        .spans
+        .into_iter()
+        .map(|(start_line, end_line)| {
+            let start = start_line.saturating_sub(1).min(lines.len());
+            let end = end_line.min(lines.len());
+            let (branch_points, _) = count_branches_and_comments(&lines[start..end], def);
+            1.0 + branch_points as f64
+        })
+        .collect()
+}
+
+#[derive(Default)]
+struct FunctionSpanVisitor {
+    spans: Vec<(usize, usize)>,
+}
+
+impl<'ast> Visit<'ast> for FunctionSpanVisitor {
+    fn visit_item_fn(&mut self, i: &'ast syn::ItemFn) {
+        let span = i.span();
+        self.spans.push((span.start().line, span.end().line));
+        visit::visit_item_fn(self, i);
+    }
+
+    fn visit_impl_item_fn(&mut self, i: &'ast syn::ImplItemFn) {
+        let span = i.span();
+        self.spans.push((span.start().line, span.end().line));
+        visit::visit_impl_item_fn(self, i);
+    }
+}
+
+/// Walks every line tracking block-comment nesting and string-literal state, so
+/// branch tokens are only counted when they appear as real code.
+fn count_branches_and_comments(lines: &[&str], def: &LanguageDefinition) -> (usize, usize) {
+    let mut branch_points = 0;
+    let mut comment_lines = 0;
+    let mut comment_stack: Vec<usize> = Vec::new();
+
+    for line in lines {
+        let mut in_string: Option<char> = None;
+        let mut saw_comment = !comment_stack.is_empty();
+        let bytes = line.as_bytes();
+        let mut cursor = 0usize;
 
-        for line in &lines {
-            let trimmed = line.trim();
+        while cursor < bytes.len() {
+            if let Some(&top) = comment_stack.last() {
+                let close = &def.block_comments[top].1;
+                if line[cursor..].starts_with(close.as_str()) {
+                    cursor += close.len();
+                    if def.nested_block_comments {
+                        comment_stack.pop();
+                    } else {
+                        comment_stack.clear();
+                    }
+                } else {
+                    cursor += char_len_at(line, cursor);
+                }
+                saw_comment = true;
+                continue;
+            }
+
+            if let Some(quote) = in_string {
+                if bytes[cursor] == b'\\' && cursor + 1 < bytes.len() {
+                    cursor += 1 + char_len_at(line, cursor + 1);
+                    continue;
+                }
+                if bytes[cursor] as char == quote {
+                    in_string = None;
+                }
+                cursor += char_len_at(line, cursor);
+                continue;
+            }
+
+            if bytes[cursor] == b'"' || bytes[cursor] == b'\'' {
+                in_string = Some(bytes[cursor] as char);
+                cursor += 1;
+                continue;
+            }
+
+            if def
+                .line_comments
+                .iter()
+                .any(|tok| line[cursor..].starts_with(tok.as_str()))
+            {
+                saw_comment = true;
+                break;
+            }
 
-            // Count comments
-            if trimmed.starts_with("//")
-                || trimmed.starts_with("#")
-                || trimmed.starts_with("/*")
-                || trimmed.contains("*/")
+            if let Some((idx, open_len)) =
+                def.block_comments.iter().enumerate().find_map(|(i, (open, _))| {
+                    if line[cursor..].starts_with(open.as_str()) {
+                        Some((i, open.len()))
+                    } else {
+                        None
+                    }
+                })
             {
-                comment_lines += 1;
+                comment_stack.push(idx);
+                cursor += open_len;
+                saw_comment = true;
+                continue;
             }
 
-            // Count branch points
-            if trimmed.contains("if ")
-                || trimmed.contains("else ")
-                || trimmed.contains("match ")
-                || trimmed.contains("while ")
-                || trimmed.contains("for ")
-                || trimmed.contains("&&")
-                || trimmed.contains("||")
+            if let Some(token) = def
+                .branch_tokens
+                .iter()
+                .find(|tok| line[cursor..].starts_with(tok.as_str()) && is_token_boundary(line, cursor, tok))
             {
                 branch_points += 1;
+                cursor += token.len();
+                continue;
             }
+
+            cursor += char_len_at(line, cursor);
         }
 
-        complexity.cyclomatic_complexity = 1.0 + branch_points as f64;
-        complexity.comment_ratio = if !lines.is_empty() {
-            comment_lines as f64 / lines.len() as f64
-        } else {
-            0.0
-        };
+        if saw_comment {
+            comment_lines += 1;
+        }
+    }
 
-        complexity
+    (branch_points, comment_lines)
+}
+
+/// Byte width of the char starting at `idx` in `line`, so a scanner cursor stepping
+/// past "one ordinary character" lands back on a char boundary instead of splitting
+/// a multi-byte UTF-8 sequence (which would panic on the next `line[idx..]` slice).
+fn char_len_at(line: &str, idx: usize) -> usize {
+    line[idx..].chars().next().map_or(1, |c| c.len_utf8())
+}
+
+/// Whole-word matching for alphabetic branch tokens (`"if"` shouldn't match inside
+/// `"ifdef"`); operator tokens like `&&`/`||` have no word boundary to check.
+fn is_token_boundary(line: &str, start: usize, token: &str) -> bool {
+    let is_word_token = token
+        .chars()
+        .next()
+        .map(|c| c.is_alphanumeric() || c == '_')
+        .unwrap_or(false);
+    if !is_word_token {
+        return true;
+    }
+
+    let before_ok = line[..start]
+        .chars()
+        .next_back()
+        .map(|c| !c.is_alphanumeric() && c != '_')
+        .unwrap_or(true);
+    let end = start + token.len();
+    let after_ok = line[end..]
+        .chars()
+        .next()
+        .map(|c| !c.is_alphanumeric() && c != '_')
+        .unwrap_or(true);
+
+    before_ok && after_ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `count_branches_and_comments` used to step its scanner cursor one byte at a
+    /// time, then slice the line at that cursor - a multi-byte char anywhere on a
+    /// line (inside a string, a comment, or plain code) landed the cursor
+    /// mid-character and panicked.
+    #[test]
+    fn analyze_file_handles_multibyte_utf8() {
+        let detector = LanguageDetector::new();
+        let analyzer = ComplexityAnalyzer::new();
+        let content = "// café\nif naïve { println!(\"🎉\"); }\n";
+        let complexity = analyzer.analyze_file(content, Path::new("x.rs"), "Rust", &detector);
+        assert_eq!(complexity.cyclomatic_complexity, 2.0);
     }
 }
@@ -0,0 +1,111 @@
+use chrono::{DateTime, Utc};
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::processor::types::EnhancedFileStats;
+
+/// Rolled-up totals for one directory's full subtree (every file at every depth
+/// below it): aggregated size, line counts, mean cyclomatic complexity, and the
+/// most recent mtime among its files (the directory's "effective" last-modified).
+#[derive(Debug, Clone, Default)]
+pub struct DirectoryRollup {
+    pub file_count: usize,
+    pub total_size: u64,
+    pub total_lines: usize,
+    pub code_lines: usize,
+    pub comment_lines: usize,
+    pub blank_lines: usize,
+    total_complexity: f64,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+impl DirectoryRollup {
+    pub fn mean_complexity(&self) -> f64 {
+        if self.file_count == 0 {
+            0.0
+        } else {
+            self.total_complexity / self.file_count as f64
+        }
+    }
+
+    fn merge(&mut self, other: &DirectoryRollup) {
+        self.file_count += other.file_count;
+        self.total_size += other.total_size;
+        self.total_lines += other.total_lines;
+        self.code_lines += other.code_lines;
+        self.comment_lines += other.comment_lines;
+        self.blank_lines += other.blank_lines;
+        self.total_complexity += other.total_complexity;
+        self.last_modified = match (self.last_modified, other.last_modified) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (Some(a), None) => Some(a),
+            (None, b) => b,
+        };
+    }
+}
+
+/// For every ancestor directory of every processed file (keyed by its path
+/// relative to `base_path`, with the root itself keyed as `.`), rolls up the
+/// subtree's totals. Each file independently builds a tiny per-ancestor map, and
+/// rayon folds those together in parallel - the same `par_iter().reduce()` shape
+/// `MetricsAnalyzer` uses for its own aggregation - rather than walking the
+/// directory tree a second time serially after the main analysis pass.
+pub fn compute_directory_rollups(
+    stats: &EnhancedFileStats,
+    base_path: &Path,
+) -> HashMap<PathBuf, DirectoryRollup> {
+    stats
+        .file_statistics
+        .values()
+        .collect::<Vec<_>>()
+        .par_iter()
+        .map(|file_stat| {
+            let leaf = DirectoryRollup {
+                file_count: 1,
+                total_size: file_stat.size,
+                total_lines: file_stat.lines,
+                code_lines: file_stat.code,
+                comment_lines: file_stat.comments,
+                blank_lines: file_stat.blanks,
+                total_complexity: file_stat.complexity.cyclomatic_complexity,
+                last_modified: Some(file_stat.last_modified),
+            };
+
+            let mut partial: HashMap<PathBuf, DirectoryRollup> = HashMap::new();
+            for ancestor in ancestors_relative_to(&file_stat.path, base_path) {
+                partial
+                    .entry(ancestor)
+                    .and_modify(|rollup| rollup.merge(&leaf))
+                    .or_insert_with(|| leaf.clone());
+            }
+            partial
+        })
+        .reduce(HashMap::new, |mut a, b| {
+            for (dir, rollup) in b {
+                a.entry(dir)
+                    .and_modify(|existing| existing.merge(&rollup))
+                    .or_insert(rollup);
+            }
+            a
+        })
+}
+
+/// Every directory from the file's immediate parent up to (and including) the
+/// workspace root, expressed relative to `base_path` with the root itself as `.`.
+fn ancestors_relative_to(path: &Path, base_path: &Path) -> Vec<PathBuf> {
+    let relative = path.strip_prefix(base_path).unwrap_or(path);
+    let mut ancestors = Vec::new();
+    let mut current = relative.parent();
+
+    while let Some(dir) = current {
+        if dir.as_os_str().is_empty() {
+            ancestors.push(PathBuf::from("."));
+            break;
+        }
+        ancestors.push(dir.to_path_buf());
+        current = dir.parent();
+    }
+
+    ancestors
+}
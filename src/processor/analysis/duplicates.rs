@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+use std::fs;
+use std::hash::Hasher;
+use std::path::{Path, PathBuf};
+
+use twox_hash::XxHash64;
+
+use crate::processor::types::FileStatistics;
+
+/// A set of files confirmed to share identical content.
+#[derive(Debug, Clone)]
+pub struct DuplicateGroup {
+    pub size: u64,
+    pub paths: Vec<PathBuf>,
+}
+
+impl DuplicateGroup {
+    /// Bytes that could be reclaimed by keeping a single copy of this group.
+    pub fn wasted_bytes(&self) -> u64 {
+        (self.paths.len() as u64 - 1) * self.size
+    }
+}
+
+pub struct DuplicateDetector;
+
+impl DuplicateDetector {
+    /// Finds files with identical content among `file_statistics`.
+    ///
+    /// Runs in three stages so the common case (mostly-unique files) never touches disk
+    /// beyond the size metadata already collected:
+    /// 1. Bucket by exact byte size; a unique size can never have a duplicate.
+    /// 2. Within a size bucket, hash file contents with a fast non-cryptographic hash and
+    ///    group by that hash.
+    /// 3. Within a hash collision, compare bytes directly to confirm a true duplicate.
+    pub fn find_duplicates(file_statistics: &HashMap<PathBuf, FileStatistics>) -> Vec<DuplicateGroup> {
+        let mut by_size: HashMap<u64, Vec<&Path>> = HashMap::new();
+        for (path, stat) in file_statistics {
+            if stat.size == 0 {
+                continue;
+            }
+            by_size.entry(stat.size).or_default().push(path);
+        }
+
+        let mut groups = Vec::new();
+        for (size, paths) in by_size {
+            if paths.len() < 2 {
+                continue;
+            }
+
+            let mut by_hash: HashMap<u64, Vec<&Path>> = HashMap::new();
+            for path in paths {
+                if let Some(hash) = hash_file(path) {
+                    by_hash.entry(hash).or_default().push(path);
+                }
+            }
+
+            for (_, candidates) in by_hash {
+                if candidates.len() < 2 {
+                    continue;
+                }
+
+                for confirmed in confirm_duplicates(&candidates) {
+                    if confirmed.len() > 1 {
+                        groups.push(DuplicateGroup {
+                            size,
+                            paths: confirmed.into_iter().map(Path::to_path_buf).collect(),
+                        });
+                    }
+                }
+            }
+        }
+
+        groups.sort_by(|a, b| b.wasted_bytes().cmp(&a.wasted_bytes()));
+        groups
+    }
+}
+
+fn hash_file(path: &Path) -> Option<u64> {
+    let bytes = fs::read(path).ok()?;
+    let mut hasher = XxHash64::default();
+    hasher.write(&bytes);
+    Some(hasher.finish())
+}
+
+/// Splits hash-colliding candidates into groups of byte-for-byte identical files.
+fn confirm_duplicates<'a>(candidates: &[&'a Path]) -> Vec<Vec<&'a Path>> {
+    let contents: Vec<(&Path, Vec<u8>)> = candidates
+        .iter()
+        .filter_map(|&path| fs::read(path).ok().map(|bytes| (path, bytes)))
+        .collect();
+
+    let mut used = vec![false; contents.len()];
+    let mut groups: Vec<Vec<&Path>> = Vec::new();
+
+    for i in 0..contents.len() {
+        if used[i] {
+            continue;
+        }
+        used[i] = true;
+        let mut group = vec![contents[i].0];
+
+        for j in (i + 1)..contents.len() {
+            if !used[j] && contents[j].1 == contents[i].1 {
+                used[j] = true;
+                group.push(contents[j].0);
+            }
+        }
+
+        groups.push(group);
+    }
+
+    groups
+}
@@ -0,0 +1,119 @@
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Commit-log data for a single file: how often it changes and who last touched it.
+#[derive(Debug, Clone, Default)]
+pub struct FileChurn {
+    pub commit_count: usize,
+    pub last_author: String,
+    pub last_commit: Option<DateTime<Utc>>,
+}
+
+/// A file that is both frequently changed and complex - the best refactor candidates.
+#[derive(Debug, Clone)]
+pub struct Hotspot {
+    pub path: PathBuf,
+    pub complexity: f64,
+    pub commits: usize,
+}
+
+/// Repository-wide churn and authorship data, built by walking `git log` once.
+#[derive(Debug, Default)]
+pub struct GitHistory {
+    pub total_commits: usize,
+    pub top_authors: Vec<(String, usize)>,
+    pub last_commit_date: Option<DateTime<Utc>>,
+    file_churn: HashMap<PathBuf, FileChurn>,
+}
+
+impl GitHistory {
+    /// Walks the commit log rooted at `dir` and builds per-file churn/authorship data.
+    /// Returns `None` when `dir` isn't inside a git repository or `git` isn't available,
+    /// so callers can degrade gracefully to filesystem mtimes.
+    pub fn collect(dir: &Path) -> Option<Self> {
+        let repo_root = git_toplevel(dir)?;
+
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(&repo_root)
+            .args([
+                "log",
+                "--name-only",
+                "--pretty=format:COMMIT\t%H\t%an\t%ad",
+                "--date=iso-strict",
+            ])
+            .output()
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        let text = String::from_utf8_lossy(&output.stdout);
+
+        let mut history = GitHistory::default();
+        let mut author_counts: HashMap<String, usize> = HashMap::new();
+        let mut current_author = String::new();
+        let mut current_date: Option<DateTime<Utc>> = None;
+
+        for line in text.lines() {
+            if let Some(rest) = line.strip_prefix("COMMIT\t") {
+                let mut parts = rest.splitn(3, '\t');
+                let _hash = parts.next().unwrap_or_default();
+                current_author = parts.next().unwrap_or_default().to_string();
+                current_date = parts
+                    .next()
+                    .and_then(|d| DateTime::parse_from_rfc3339(d).ok())
+                    .map(|d| d.with_timezone(&Utc));
+
+                history.total_commits += 1;
+                *author_counts.entry(current_author.clone()).or_insert(0) += 1;
+                if history.last_commit_date.is_none() {
+                    history.last_commit_date = current_date;
+                }
+            } else if !line.trim().is_empty() {
+                let absolute = repo_root.join(line);
+                let key = fs::canonicalize(&absolute).unwrap_or(absolute);
+                let churn = history.file_churn.entry(key).or_default();
+                churn.commit_count += 1;
+                if churn.last_commit.is_none() {
+                    churn.last_author = current_author.clone();
+                    churn.last_commit = current_date;
+                }
+            }
+        }
+
+        let mut top_authors: Vec<(String, usize)> = author_counts.into_iter().collect();
+        top_authors.sort_by(|a, b| b.1.cmp(&a.1));
+        top_authors.truncate(5);
+        history.top_authors = top_authors;
+
+        Some(history)
+    }
+
+    /// Looks up churn data for `path`, matching it against the canonicalized paths
+    /// recorded from the commit log regardless of how `path` itself is represented.
+    pub fn churn_for(&self, path: &Path) -> Option<&FileChurn> {
+        let key = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        self.file_churn.get(&key)
+    }
+}
+
+fn git_toplevel(dir: &Path) -> Option<PathBuf> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["rev-parse", "--show-toplevel"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let path = String::from_utf8(output.stdout).ok()?;
+    Some(PathBuf::from(path.trim()))
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Per-path working-tree status, built by running `git status` once for an entire
+/// repository rather than shelling out per file or per directory.
+#[derive(Debug, Default)]
+pub struct GitStatus {
+    codes: HashMap<PathBuf, (char, char)>,
+}
+
+impl GitStatus {
+    /// Runs `git status --porcelain` rooted at `dir` and records the index/worktree
+    /// status for every path it reports. Returns `None` when `dir` isn't inside a git
+    /// repository or `git` isn't available, so callers can skip the annotation entirely.
+    pub fn collect(dir: &Path) -> Option<Self> {
+        let repo_root = git_toplevel(dir)?;
+
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(&repo_root)
+            .args(["status", "--porcelain=v1", "-z", "--ignored"])
+            .output()
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        let mut codes = HashMap::new();
+        let mut fields = output
+            .stdout
+            .split(|&b| b == 0)
+            .filter(|field| !field.is_empty());
+
+        while let Some(raw) = fields.next() {
+            let entry = String::from_utf8_lossy(raw);
+            if entry.len() < 4 {
+                continue;
+            }
+
+            let mut chars = entry.chars();
+            let index_status = chars.next().unwrap_or(' ');
+            let worktree_status = chars.next().unwrap_or(' ');
+            let rel_path = &entry[3..];
+
+            let absolute = repo_root.join(rel_path);
+            let key = fs::canonicalize(&absolute).unwrap_or(absolute);
+            codes.insert(key, (index_status, worktree_status));
+
+            // A rename/copy entry is followed by its original path as a second
+            // NUL-separated field; it's not a path being reported on, so skip it.
+            if index_status == 'R' || index_status == 'C' {
+                fields.next();
+            }
+        }
+
+        Some(Self { codes })
+    }
+
+    /// Compact two-character porcelain marker (index status, worktree status) for
+    /// `path`, e.g. `"M "` or `"??"`, or `None` when the path is unmodified.
+    pub fn marker_for(&self, path: &Path) -> Option<String> {
+        let key = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        let (x, y) = self.codes.get(&key)?;
+        Some(format!("{}{}", x, y))
+    }
+}
+
+fn git_toplevel(dir: &Path) -> Option<PathBuf> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["rev-parse", "--show-toplevel"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let path = String::from_utf8(output.stdout).ok()?;
+    Some(PathBuf::from(path.trim()))
+}
@@ -0,0 +1,399 @@
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+
+use crate::processor::analysis::DependencyAnalyzer;
+
+/// What kind of item a [`SymbolReference`] points at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Archive, RkyvSerialize, RkyvDeserialize)]
+#[archive(check_bytes)]
+pub enum SymbolKind {
+    Struct,
+    Enum,
+    Trait,
+    Function,
+    Method,
+    Const,
+    Static,
+    TypeAlias,
+    Module,
+    TypeParam,
+    Lifetime,
+    ConstParam,
+}
+
+impl SymbolKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SymbolKind::Struct => "struct",
+            SymbolKind::Enum => "enum",
+            SymbolKind::Trait => "trait",
+            SymbolKind::Function => "function",
+            SymbolKind::Method => "method",
+            SymbolKind::Const => "const",
+            SymbolKind::Static => "static",
+            SymbolKind::TypeAlias => "type alias",
+            SymbolKind::Module => "module",
+            SymbolKind::TypeParam => "type parameter",
+            SymbolKind::Lifetime => "lifetime parameter",
+            SymbolKind::ConstParam => "const parameter",
+        }
+    }
+
+    /// Parses a `--kind`/`--list-kinds` argument, accepting either `as_str()`'s own
+    /// output or a few common aliases (`class` for [`SymbolKind::Struct`], `interface`
+    /// for [`SymbolKind::Trait`]) so users coming from other languages aren't stuck
+    /// guessing Rust terminology.
+    pub fn parse(s: &str) -> Option<Self> {
+        Some(match s.to_lowercase().as_str() {
+            "struct" | "class" => SymbolKind::Struct,
+            "enum" => SymbolKind::Enum,
+            "trait" | "interface" => SymbolKind::Trait,
+            "function" | "fn" => SymbolKind::Function,
+            "method" => SymbolKind::Method,
+            "const" => SymbolKind::Const,
+            "static" => SymbolKind::Static,
+            "type alias" | "type-alias" | "typealias" => SymbolKind::TypeAlias,
+            "module" | "mod" => SymbolKind::Module,
+            "type parameter" | "type-param" | "typeparam" => SymbolKind::TypeParam,
+            "lifetime parameter" | "lifetime" => SymbolKind::Lifetime,
+            "const parameter" | "const-param" | "constparam" => SymbolKind::ConstParam,
+            _ => return None,
+        })
+    }
+}
+
+/// A single symbol definition or use site recorded by [`CodeIndex`].
+#[derive(Debug, Clone, Serialize, Deserialize, Archive, RkyvSerialize, RkyvDeserialize)]
+#[archive(check_bytes)]
+pub struct SymbolReference {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// Dotted/`::`-joined path from the enclosing modules (and, for methods, the
+    /// owning type) down to this symbol.
+    pub qualified_path: String,
+    pub file: PathBuf,
+    pub line: usize,
+    pub context: String,
+}
+
+/// Cross-file index of Rust symbol definitions, built by walking every `.rs` file
+/// with [`syn`] and persisted to `index_path` so later runs can load it instead of
+/// reparsing the whole tree.
+#[derive(Default, Serialize, Deserialize, Archive, RkyvSerialize, RkyvDeserialize)]
+#[archive(check_bytes)]
+pub struct CodeIndex {
+    #[serde(skip)]
+    #[with(rkyv::with::Skip)]
+    index_path: PathBuf,
+    symbols: HashMap<String, Vec<SymbolReference>>,
+}
+
+impl CodeIndex {
+    /// Loads a previously persisted index, preferring the `.rkyv` sidecar (validated
+    /// and deserialized via zero-copy mmap) when present, and falling back to the
+    /// human-readable `symbols.json` otherwise.
+    pub fn new(index_path: &PathBuf) -> io::Result<Self> {
+        let rkyv_file = index_path.join("symbols.rkyv");
+        if rkyv_file.exists() {
+            return Self::load_rkyv(&rkyv_file, index_path);
+        }
+
+        let json_file = index_path.join("symbols.json");
+        if json_file.exists() {
+            Self::load(&json_file)
+        } else {
+            Ok(Self {
+                index_path: index_path.clone(),
+                symbols: HashMap::new(),
+            })
+        }
+    }
+
+    fn load(file: &Path) -> io::Result<Self> {
+        let raw = fs::read_to_string(file)?;
+        let mut index: Self =
+            serde_json::from_str(&raw).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        index.index_path = file
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .to_path_buf();
+        Ok(index)
+    }
+
+    /// Memory-maps `file` and validates it in place with `rkyv::check_archived_root`
+    /// before deserializing, so a corrupt or truncated sidecar is rejected without
+    /// ever walking it as trusted data.
+    fn load_rkyv(file: &Path, index_path: &Path) -> io::Result<Self> {
+        let handle = fs::File::open(file)?;
+        let mmap = unsafe { memmap2::Mmap::map(&handle)? };
+        let archived = rkyv::check_archived_root::<Self>(&mmap)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        let mut index: Self = archived
+            .deserialize(&mut rkyv::Infallible)
+            .expect("Infallible deserializer never fails");
+        index.index_path = index_path.to_path_buf();
+        Ok(index)
+    }
+
+    /// Walks every `.rs` file under `root`, indexes its symbol definitions, and
+    /// persists the result under `index_path`.
+    pub fn build(root: &Path, index_path: &Path) -> io::Result<Self> {
+        let mut index = Self {
+            index_path: index_path.to_path_buf(),
+            symbols: HashMap::new(),
+        };
+        index.index_directory(root)?;
+        index.save()?;
+        Ok(index)
+    }
+
+    fn index_directory(&mut self, dir: &Path) -> io::Result<()> {
+        if !dir.is_dir() {
+            return Ok(());
+        }
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.is_dir() {
+                if matches!(
+                    path.file_name().and_then(|n| n.to_str()),
+                    Some("target" | ".git")
+                ) {
+                    continue;
+                }
+                self.index_directory(&path)?;
+            } else if path.extension().and_then(|e| e.to_str()) == Some("rs") {
+                self.index_file(&path);
+            }
+        }
+        Ok(())
+    }
+
+    fn index_file(&mut self, path: &Path) {
+        let Ok(content) = fs::read_to_string(path) else {
+            return;
+        };
+        let Ok(syntax) = syn::parse_file(&content) else {
+            return;
+        };
+
+        let mut visitor = RustVisitor::new(path);
+        visitor.visit_file(&syntax);
+        for symbol in visitor.symbols {
+            self.symbols.entry(symbol.name.clone()).or_default().push(symbol);
+        }
+    }
+
+    /// Writes both the human-readable `symbols.json` and a `symbols.rkyv` sidecar;
+    /// `new` prefers the sidecar so later runs skip the JSON parse entirely.
+    pub fn save(&self) -> io::Result<()> {
+        fs::create_dir_all(&self.index_path)?;
+        let raw = serde_json::to_string_pretty(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(self.index_path.join("symbols.json"), raw)?;
+
+        let bytes = rkyv::to_bytes::<_, 4096>(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        fs::write(self.index_path.join("symbols.rkyv"), bytes)
+    }
+
+    /// Returns every definition named `name`, falling back to a case-insensitive
+    /// substring match over all indexed names when there's no exact hit.
+    pub fn find_symbol(&self, name: &str) -> io::Result<Option<Vec<SymbolReference>>> {
+        if let Some(exact) = self.symbols.get(name) {
+            return Ok(Some(exact.clone()));
+        }
+
+        let needle = name.to_lowercase();
+        let matches: Vec<SymbolReference> = self
+            .symbols
+            .iter()
+            .filter(|(key, _)| key.to_lowercase().contains(&needle))
+            .flat_map(|(_, refs)| refs.clone())
+            .collect();
+
+        Ok(if matches.is_empty() { None } else { Some(matches) })
+    }
+
+    /// Returns every indexed symbol of the given `kind`, sorted by file then line so
+    /// results read top-to-bottom the way they appear in the workspace.
+    pub fn symbols_of_kind(&self, kind: SymbolKind) -> Vec<&SymbolReference> {
+        let mut matches: Vec<&SymbolReference> = self
+            .symbols
+            .values()
+            .flatten()
+            .filter(|reference| reference.kind == kind)
+            .collect();
+        matches.sort_by(|a, b| a.file.cmp(&b.file).then(a.line.cmp(&b.line)));
+        matches
+    }
+
+    /// Locates use sites for `name`: functions/methods that call it (via `deps`'
+    /// `function_calls` map) and files that import it (via `deps`' `imports` map).
+    pub fn find_references(&self, name: &str, deps: &DependencyAnalyzer) -> Vec<SymbolReference> {
+        let mut references = Vec::new();
+
+        for (caller, callees) in deps.function_calls() {
+            if callees.iter().any(|callee| callee == name) {
+                if let Some(defs) = self.symbols.get(caller) {
+                    references.extend(defs.iter().cloned());
+                }
+            }
+        }
+
+        for (file, uses) in deps.imports() {
+            if uses.iter().any(|import| import == name) {
+                references.push(SymbolReference {
+                    name: name.to_string(),
+                    kind: SymbolKind::Module,
+                    qualified_path: name.to_string(),
+                    file: file.clone(),
+                    line: 0,
+                    context: "use".to_string(),
+                });
+            }
+        }
+
+        references
+    }
+}
+
+struct RustVisitor {
+    path: PathBuf,
+    module_stack: Vec<String>,
+    impl_stack: Vec<String>,
+    symbols: Vec<SymbolReference>,
+}
+
+impl RustVisitor {
+    fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            module_stack: Vec::new(),
+            impl_stack: Vec::new(),
+            symbols: Vec::new(),
+        }
+    }
+
+    fn qualify(&self, name: &str) -> String {
+        let mut segments = self.module_stack.clone();
+        segments.extend(self.impl_stack.clone());
+        segments.push(name.to_string());
+        segments.join("::")
+    }
+
+    fn push_symbol(&mut self, name: &str, kind: SymbolKind, line: usize, context: &str) {
+        self.symbols.push(SymbolReference {
+            name: name.to_string(),
+            kind,
+            qualified_path: self.qualify(name),
+            file: self.path.clone(),
+            line,
+            context: context.to_string(),
+        });
+    }
+
+    fn push_generics(&mut self, generics: &syn::Generics, owner_line: usize) {
+        for param in &generics.params {
+            match param {
+                syn::GenericParam::Type(t) => {
+                    self.push_symbol(&t.ident.to_string(), SymbolKind::TypeParam, owner_line, "generic parameter")
+                }
+                syn::GenericParam::Lifetime(l) => self.push_symbol(
+                    &l.lifetime.ident.to_string(),
+                    SymbolKind::Lifetime,
+                    owner_line,
+                    "lifetime parameter",
+                ),
+                syn::GenericParam::Const(c) => {
+                    self.push_symbol(&c.ident.to_string(), SymbolKind::ConstParam, owner_line, "const parameter")
+                }
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for RustVisitor {
+    fn visit_item_struct(&mut self, i: &'ast syn::ItemStruct) {
+        let line = i.span().start().line;
+        self.push_symbol(&i.ident.to_string(), SymbolKind::Struct, line, "struct definition");
+        self.push_generics(&i.generics, line);
+        visit::visit_item_struct(self, i);
+    }
+
+    fn visit_item_enum(&mut self, i: &'ast syn::ItemEnum) {
+        let line = i.span().start().line;
+        self.push_symbol(&i.ident.to_string(), SymbolKind::Enum, line, "enum definition");
+        self.push_generics(&i.generics, line);
+        visit::visit_item_enum(self, i);
+    }
+
+    fn visit_item_trait(&mut self, i: &'ast syn::ItemTrait) {
+        let line = i.span().start().line;
+        self.push_symbol(&i.ident.to_string(), SymbolKind::Trait, line, "trait definition");
+        self.push_generics(&i.generics, line);
+        visit::visit_item_trait(self, i);
+    }
+
+    fn visit_item_fn(&mut self, i: &'ast syn::ItemFn) {
+        let line = i.span().start().line;
+        self.push_symbol(&i.sig.ident.to_string(), SymbolKind::Function, line, "function definition");
+        self.push_generics(&i.sig.generics, line);
+        visit::visit_item_fn(self, i);
+    }
+
+    fn visit_item_const(&mut self, i: &'ast syn::ItemConst) {
+        let line = i.span().start().line;
+        self.push_symbol(&i.ident.to_string(), SymbolKind::Const, line, "const definition");
+        visit::visit_item_const(self, i);
+    }
+
+    fn visit_item_static(&mut self, i: &'ast syn::ItemStatic) {
+        let line = i.span().start().line;
+        self.push_symbol(&i.ident.to_string(), SymbolKind::Static, line, "static definition");
+        visit::visit_item_static(self, i);
+    }
+
+    fn visit_item_type(&mut self, i: &'ast syn::ItemType) {
+        let line = i.span().start().line;
+        self.push_symbol(&i.ident.to_string(), SymbolKind::TypeAlias, line, "type alias");
+        self.push_generics(&i.generics, line);
+        visit::visit_item_type(self, i);
+    }
+
+    fn visit_item_mod(&mut self, i: &'ast syn::ItemMod) {
+        let line = i.span().start().line;
+        self.push_symbol(&i.ident.to_string(), SymbolKind::Module, line, "module definition");
+        self.module_stack.push(i.ident.to_string());
+        visit::visit_item_mod(self, i);
+        self.module_stack.pop();
+    }
+
+    fn visit_item_impl(&mut self, i: &'ast syn::ItemImpl) {
+        self.impl_stack.push(type_name(&i.self_ty));
+        visit::visit_item_impl(self, i);
+        self.impl_stack.pop();
+    }
+
+    fn visit_impl_item_fn(&mut self, i: &'ast syn::ImplItemFn) {
+        let line = i.span().start().line;
+        self.push_symbol(&i.sig.ident.to_string(), SymbolKind::Method, line, "method definition");
+        self.push_generics(&i.sig.generics, line);
+        visit::visit_impl_item_fn(self, i);
+    }
+}
+
+fn type_name(ty: &syn::Type) -> String {
+    if let syn::Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            return segment.ident.to_string();
+        }
+    }
+    "_".to_string()
+}
@@ -1,8 +1,52 @@
+use serde::Deserialize;
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// Raw, data-driven definition of a language as loaded from `languages.json`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LanguageDefinition {
+    pub extensions: Vec<String>,
+    pub line_comments: Vec<String>,
+    pub block_comments: Vec<(String, String)>,
+    #[serde(default)]
+    pub nested_block_comments: bool,
+    /// Multi-character string/verbatim-string delimiter pairs that can span several
+    /// physical lines (e.g. Python's `'''`/`'''` used as a plain string rather than a
+    /// docstring). Content between a matched pair counts as code, and comment tokens
+    /// inside it are ignored, the same way code after a closed block comment is.
+    /// Ordinary single-line `"`/`'` quoting is handled unconditionally by the scanner
+    /// and doesn't need a declaration here.
+    #[serde(default)]
+    pub string_delimiters: Vec<(String, String)>,
+    /// Tokens that introduce a branch for cyclomatic-complexity scoring (e.g. `if`,
+    /// `elif`, `&&`), matched whole-word for identifier-like tokens.
+    #[serde(default)]
+    pub branch_tokens: Vec<String>,
+}
+
+/// Classification of a single physical line produced by [`LanguageDetector::count_lines`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineKind {
+    Code,
+    Comment,
+    Blank,
+}
+
+/// Code/comment/blank counts for a whole file, as produced by the multi-line scanner.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LineCounts {
+    pub code: usize,
+    pub comments: usize,
+    pub blanks: usize,
+}
+
+const EMBEDDED_LANGUAGES: &str = include_str!("languages.json");
 
 pub struct LanguageDetector {
-    language_patterns: HashMap<String, Vec<String>>,
+    /// Language name -> definition, loaded from the embedded (or user-supplied) `languages.json`.
+    definitions: HashMap<String, LanguageDefinition>,
+    /// Extension (lowercase, no dot) -> language name, derived from `definitions`.
+    extension_index: HashMap<String, String>,
 }
 
 impl Default for LanguageDetector {
@@ -12,45 +56,50 @@ impl Default for LanguageDetector {
 }
 
 impl LanguageDetector {
+    /// Builds a detector from the table compiled into the binary at build time.
     pub fn new() -> Self {
-        let mut language_patterns = HashMap::new();
-
-        // Rust
-        language_patterns.insert(
-            "Rust".to_string(),
-            vec![
-                ".rs".to_string(),
-                "fn ".to_string(),
-                "impl ".to_string(),
-                "pub ".to_string(),
-            ],
-        );
-
-        // Python
-        language_patterns.insert(
-            "Python".to_string(),
-            vec![
-                ".py".to_string(),
-                "def ".to_string(),
-                "import ".to_string(),
-                "class ".to_string(),
-            ],
-        );
-
-        // JavaScript
-        language_patterns.insert(
-            "JavaScript".to_string(),
-            vec![
-                ".js".to_string(),
-                "function ".to_string(),
-                "const ".to_string(),
-                "let ".to_string(),
-            ],
-        );
-
-        // Add more languages as needed...
-
-        Self { language_patterns }
+        let definitions: HashMap<String, LanguageDefinition> =
+            serde_json::from_str(EMBEDDED_LANGUAGES)
+                .expect("embedded languages.json must be valid");
+        Self::from_definitions(definitions)
+    }
+
+    /// Builds a detector from a user-supplied `languages.json`, falling back to the
+    /// embedded table if the file can't be read or parsed.
+    pub fn with_overrides(path: &Path) -> Self {
+        match std::fs::read_to_string(path) {
+            Ok(raw) => match serde_json::from_str::<HashMap<String, LanguageDefinition>>(&raw) {
+                Ok(definitions) => return Self::from_definitions(definitions),
+                Err(e) => {
+                    eprintln!(
+                        "⚠️  Failed to parse language definitions at {}: {}",
+                        path.display(),
+                        e
+                    );
+                }
+            },
+            Err(e) => {
+                eprintln!(
+                    "⚠️  Failed to read language definitions at {}: {}",
+                    path.display(),
+                    e
+                );
+            }
+        }
+        Self::new()
+    }
+
+    fn from_definitions(definitions: HashMap<String, LanguageDefinition>) -> Self {
+        let mut extension_index = HashMap::new();
+        for (language, def) in &definitions {
+            for ext in &def.extensions {
+                extension_index.insert(ext.to_lowercase(), language.clone());
+            }
+        }
+        Self {
+            definitions,
+            extension_index,
+        }
     }
 
     pub fn detect_language(&self, path: &Path, content: &str) -> String {
@@ -60,32 +109,206 @@ impl LanguageDetector {
             .unwrap_or("")
             .to_lowercase();
 
-        // First try by extension
-        match ext.as_str() {
-            "rs" => return "Rust".to_string(),
-            "py" => return "Python".to_string(),
-            "js" => return "JavaScript".to_string(),
-            "java" => return "Java".to_string(),
-            "cpp" | "hpp" => return "C++".to_string(),
-            "c" | "h" => return "C".to_string(),
-            // Add more direct mappings...
-            _ => {}
-        }
-
-        // If extension is ambiguous, analyze content
-        let mut scores = HashMap::new();
-        for (language, patterns) in &self.language_patterns {
-            let score = patterns
-                .iter()
-                .filter(|pattern| content.contains(pattern.as_str()))
-                .count();
-            scores.insert(language, score);
+        if let Some(language) = self.extension_index.get(&ext) {
+            return language.clone();
         }
 
-        scores
-            .into_iter()
+        // Extension is unknown: fall back to scoring each language's comment tokens
+        // as crude content fingerprints.
+        self.definitions
+            .iter()
+            .map(|(language, def)| {
+                let score = def
+                    .line_comments
+                    .iter()
+                    .chain(def.block_comments.iter().map(|(open, _)| open))
+                    .filter(|token| content.contains(token.as_str()))
+                    .count();
+                (language, score)
+            })
             .max_by_key(|&(_, score)| score)
+            .filter(|&(_, score)| score > 0)
             .map(|(lang, _)| lang.clone())
             .unwrap_or_else(|| "Unknown".to_string())
     }
+
+    pub fn definition(&self, language: &str) -> Option<&LanguageDefinition> {
+        self.definitions.get(language)
+    }
+
+    /// Classifies every physical line of `content` as code, comment, or blank with a
+    /// small state machine keyed by `language`'s definition: a nesting-depth stack
+    /// for block comments (popped on close, cleared outright for non-nesting
+    /// languages), an index tracking an open multi-line `string_delimiters` span, and
+    /// a same-line quote flag for ordinary `"`/`'` strings. Comment tokens found
+    /// while either string state is active are ignored, and vice versa - a `//`
+    /// inside a string literal doesn't start a comment, and a `"` inside a line
+    /// comment doesn't open a string. A line that closes a block comment or string
+    /// and carries code afterwards counts as code.
+    pub fn count_lines(&self, language: &str, content: &str) -> LineCounts {
+        let default_def = LanguageDefinition {
+            extensions: Vec::new(),
+            line_comments: Vec::new(),
+            block_comments: Vec::new(),
+            nested_block_comments: false,
+            string_delimiters: Vec::new(),
+            branch_tokens: Vec::new(),
+        };
+        let def = self.definitions.get(language).unwrap_or(&default_def);
+
+        let mut counts = LineCounts::default();
+        let mut comment_stack: Vec<usize> = Vec::new(); // indices into def.block_comments
+        let mut open_string: Option<usize> = None; // index into def.string_delimiters
+
+        for line in content.lines() {
+            if line.trim().is_empty() && comment_stack.is_empty() && open_string.is_none() {
+                counts.blanks += 1;
+                continue;
+            }
+
+            let mut saw_code = false;
+            let mut saw_comment = !comment_stack.is_empty();
+            let mut in_quote: Option<char> = None;
+            let mut cursor = 0usize;
+            let bytes = line.as_bytes();
+
+            while cursor < bytes.len() {
+                if let Some(idx) = open_string {
+                    let close = &def.string_delimiters[idx].1;
+                    if line[cursor..].starts_with(close.as_str()) {
+                        cursor += close.len();
+                        open_string = None;
+                    } else {
+                        cursor += char_len_at(line, cursor);
+                    }
+                    saw_code = true;
+                    continue;
+                }
+
+                if let Some(&top) = comment_stack.last() {
+                    let close = &def.block_comments[top].1;
+                    if line[cursor..].starts_with(close.as_str()) {
+                        cursor += close.len();
+                        if def.nested_block_comments {
+                            comment_stack.pop();
+                        } else {
+                            comment_stack.clear();
+                        }
+                        saw_comment = true;
+                        continue;
+                    }
+                    // still inside a comment, advance one char at a time
+                    cursor += char_len_at(line, cursor);
+                    continue;
+                }
+
+                if let Some(quote) = in_quote {
+                    if bytes[cursor] == b'\\' && cursor + 1 < bytes.len() {
+                        cursor += 1 + char_len_at(line, cursor + 1);
+                    } else {
+                        if bytes[cursor] as char == quote {
+                            in_quote = None;
+                        }
+                        cursor += char_len_at(line, cursor);
+                    }
+                    saw_code = true;
+                    continue;
+                }
+
+                // Check for an opening multi-line string delimiter before anything
+                // comment-related, so e.g. a `"""` isn't mistaken for a line comment.
+                if let Some((idx, open_len)) =
+                    def.string_delimiters.iter().enumerate().find_map(|(i, (open, _))| {
+                        if line[cursor..].starts_with(open.as_str()) {
+                            Some((i, open.len()))
+                        } else {
+                            None
+                        }
+                    })
+                {
+                    open_string = Some(idx);
+                    cursor += open_len;
+                    saw_code = true;
+                    continue;
+                }
+
+                if bytes[cursor] == b'"' || bytes[cursor] == b'\'' {
+                    in_quote = Some(bytes[cursor] as char);
+                    cursor += 1;
+                    saw_code = true;
+                    continue;
+                }
+
+                // Not inside a comment or string: check for a line-comment token first.
+                if def
+                    .line_comments
+                    .iter()
+                    .any(|tok| line[cursor..].starts_with(tok.as_str()))
+                {
+                    saw_comment = true;
+                    break; // rest of the line is a line comment
+                }
+
+                // Check for an opening block-comment delimiter.
+                if let Some((idx, open_len)) =
+                    def.block_comments.iter().enumerate().find_map(|(i, (open, _))| {
+                        if line[cursor..].starts_with(open.as_str()) {
+                            Some((i, open.len()))
+                        } else {
+                            None
+                        }
+                    })
+                {
+                    comment_stack.push(idx);
+                    cursor += open_len;
+                    saw_comment = true;
+                    continue;
+                }
+
+                if !bytes[cursor].is_ascii_whitespace() {
+                    saw_code = true;
+                }
+                cursor += char_len_at(line, cursor);
+            }
+
+            if saw_code {
+                counts.code += 1;
+            } else if saw_comment {
+                counts.comments += 1;
+            } else {
+                counts.blanks += 1;
+            }
+        }
+
+        counts
+    }
+}
+
+/// Path to a user-supplied `languages.json`, resolved from the `--languages-file` CLI flag.
+pub fn default_definitions_path() -> PathBuf {
+    PathBuf::from("languages.json")
+}
+
+/// Byte width of the char starting at `idx` in `line`, so a scanner cursor stepping
+/// past "one ordinary character" lands back on a char boundary instead of splitting
+/// a multi-byte UTF-8 sequence (which would panic on the next `line[idx..]` slice).
+fn char_len_at(line: &str, idx: usize) -> usize {
+    line[idx..].chars().next().map_or(1, |c| c.len_utf8())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `count_lines` used to step its scanner cursor one byte at a time, then slice
+    /// the line at that cursor - a multi-byte char (emoji, accented letter, smart
+    /// quote) anywhere on a line landed the cursor mid-character and panicked.
+    #[test]
+    fn count_lines_handles_multibyte_utf8() {
+        let detector = LanguageDetector::new();
+        let content = "// café\nlet s = \"naïve 🎉\";\nlet t = '☃';\n";
+        let counts = detector.count_lines("Rust", content);
+        assert_eq!(counts.comments, 1);
+        assert_eq!(counts.code, 2);
+    }
 }
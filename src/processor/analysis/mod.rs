@@ -1,54 +1,355 @@
 pub mod complexity;
+pub mod directory_rollup;
+pub mod duplicates;
+pub mod git_history;
+pub mod git_status;
+pub mod index;
 pub mod language;
+pub mod security;
 pub mod stats;
+pub mod stats_cache;
 
 pub use self::complexity::ComplexityAnalyzer;
+pub use self::directory_rollup::{compute_directory_rollups, DirectoryRollup};
+pub use self::duplicates::{DuplicateDetector, DuplicateGroup};
+pub use self::git_history::{GitHistory, Hotspot};
+pub use self::git_status::GitStatus;
+pub use self::index::{CodeIndex, SymbolKind, SymbolReference};
 pub use self::language::LanguageDetector;
-pub use self::stats::StatsAnalyzer;
+pub use self::security::{find_security_issues, SecurityFinding, Severity};
+pub use self::stats::{ComplexityAccumulator, StatsAnalyzer};
+pub use self::stats_cache::{CachedEntry, CachedMetrics, StatsCache};
 
-use crate::processor::types::FileMetrics;
+use crate::processor::analysis::security::SecurityFindingCounts;
+use crate::processor::types::{CodeQualityMetrics, DocumentationMetrics, FileMetrics, WorkspaceMetrics};
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::fs;
 use std::io;
-use std::path::PathBuf;
-
-pub struct CodeIndex {
-    index_path: PathBuf,
-}
-
-impl CodeIndex {
-    pub fn new(_path: &PathBuf) -> io::Result<Self> {
-        Ok(Self {
-            index_path: _path.clone(),
-        })
-    }
-
-    pub fn find_symbol(&self, _name: &str) -> io::Result<Option<Vec<SymbolReference>>> {
-        Ok(None)
-    }
-}
+use std::path::{Path, PathBuf};
+use syn::visit::{self, Visit};
 
+/// Directed workspace-crate dependency graph, built by parsing every `Cargo.toml`
+/// (and the root `Cargo.lock`, when present) reachable from a scanned directory.
 pub struct DependencyAnalyzer {
     dep_path: PathBuf,
+    graph: petgraph::graph::DiGraph<String, ()>,
+    crate_nodes: HashMap<String, petgraph::graph::NodeIndex>,
+    /// Maps a source file to the crate that owns it, so per-file queries can be
+    /// answered in terms of the crate-level graph.
+    file_to_crate: HashMap<PathBuf, String>,
+    /// Caller function/method name -> names of functions it calls, collected via a
+    /// `syn` walk of each indexed file. Consumed by [`CodeIndex::find_references`].
+    function_calls: HashMap<String, Vec<String>>,
+    /// File -> names brought into scope by its `use` declarations.
+    imports: HashMap<PathBuf, Vec<String>>,
 }
 
 impl DependencyAnalyzer {
     pub fn new() -> Self {
         Self {
             dep_path: PathBuf::new(),
+            graph: petgraph::graph::DiGraph::new(),
+            crate_nodes: HashMap::new(),
+            file_to_crate: HashMap::new(),
+            function_calls: HashMap::new(),
+            imports: HashMap::new(),
         }
     }
 
+    pub fn function_calls(&self) -> &HashMap<String, Vec<String>> {
+        &self.function_calls
+    }
+
+    pub fn imports(&self) -> &HashMap<PathBuf, Vec<String>> {
+        &self.imports
+    }
+
     pub fn load_dependencies(_path: &PathBuf) -> io::Result<Self> {
-        Ok(Self {
-            dep_path: _path.clone(),
-        })
+        Ok(Self::new_with_path(_path.clone()))
+    }
+
+    fn new_with_path(path: PathBuf) -> Self {
+        Self {
+            dep_path: path,
+            ..Self::new()
+        }
+    }
+
+    fn crate_node(&mut self, name: &str) -> petgraph::graph::NodeIndex {
+        if let Some(idx) = self.crate_nodes.get(name) {
+            return *idx;
+        }
+        let idx = self.graph.add_node(name.to_string());
+        self.crate_nodes.insert(name.to_string(), idx);
+        idx
+    }
+
+    /// Walks `root` looking for `Cargo.toml` manifests, resolving
+    /// `[dependencies]`/`[dev-dependencies]`/`[build-dependencies]` and
+    /// `[workspace.members]` into graph edges, and indexing which crate owns
+    /// each `.rs` file under that manifest's `src/`.
+    pub fn scan_workspace(root: &Path) -> io::Result<Self> {
+        let mut analyzer = Self::new_with_path(root.to_path_buf());
+        let mut manifests = Vec::new();
+        collect_manifests(root, &mut manifests)?;
+
+        for manifest_path in &manifests {
+            let Ok(raw) = fs::read_to_string(manifest_path) else {
+                continue;
+            };
+            let Ok(doc) = raw.parse::<toml::Value>() else {
+                continue;
+            };
+
+            let crate_name = doc
+                .get("package")
+                .and_then(|p| p.get("name"))
+                .and_then(|n| n.as_str())
+                .map(|s| s.to_string());
+
+            if let Some(name) = &crate_name {
+                analyzer.crate_node(name);
+
+                if let Some(crate_dir) = manifest_path.parent() {
+                    index_crate_files(
+                        &crate_dir.join("src"),
+                        name,
+                        &mut analyzer.file_to_crate,
+                        &mut analyzer.function_calls,
+                        &mut analyzer.imports,
+                    );
+                }
+
+                for section in ["dependencies", "dev-dependencies", "build-dependencies"] {
+                    if let Some(table) = doc.get(section).and_then(|v| v.as_table()) {
+                        let from = analyzer.crate_node(name);
+                        for dep_name in table.keys() {
+                            let to = analyzer.crate_node(dep_name);
+                            analyzer.graph.update_edge(from, to, ());
+                        }
+                    }
+                }
+            }
+
+            if let Some(members) = doc
+                .get("workspace")
+                .and_then(|w| w.get("members"))
+                .and_then(|m| m.as_array())
+            {
+                for member in members {
+                    if let Some(pattern) = member.as_str() {
+                        analyzer.crate_node(pattern);
+                    }
+                }
+            }
+        }
+
+        Ok(analyzer)
+    }
+
+    pub fn get_dependencies(&self, file: &PathBuf) -> Vec<String> {
+        let Some(owner) = self.file_to_crate.get(file) else {
+            return Vec::new();
+        };
+        let Some(&idx) = self.crate_nodes.get(owner) else {
+            return Vec::new();
+        };
+        self.graph
+            .neighbors_directed(idx, petgraph::Direction::Outgoing)
+            .map(|n| self.graph[n].clone())
+            .collect()
+    }
+
+    pub fn get_dependents(&self, file: &PathBuf) -> Vec<String> {
+        let Some(owner) = self.file_to_crate.get(file) else {
+            return Vec::new();
+        };
+        let Some(&idx) = self.crate_nodes.get(owner) else {
+            return Vec::new();
+        };
+        self.graph
+            .neighbors_directed(idx, petgraph::Direction::Incoming)
+            .map(|n| self.graph[n].clone())
+            .collect()
+    }
+
+    /// Renders the resolved crate graph as Graphviz DOT.
+    pub fn to_dot(&self) -> String {
+        let dot = petgraph::dot::Dot::with_config(&self.graph, &[petgraph::dot::Config::EdgeNoLabel]);
+        format!("{:?}", dot)
+    }
+
+    /// Writes the resolved crate graph as Graphviz DOT, e.g. for embedding in reports.
+    pub fn export_graph(&self, output_path: &Path) -> io::Result<()> {
+        fs::write(output_path, self.to_dot())
+    }
+
+    /// Renders the caller -> callee function call graph collected while indexing
+    /// crate files, as Graphviz DOT, e.g. for embedding in reports.
+    pub fn call_graph_dot(&self) -> String {
+        let mut graph = petgraph::graph::DiGraph::<String, ()>::new();
+        let mut nodes: HashMap<String, petgraph::graph::NodeIndex> = HashMap::new();
+
+        for (caller, callees) in &self.function_calls {
+            let from = call_graph_node(&mut graph, &mut nodes, caller);
+            for callee in callees {
+                let to = call_graph_node(&mut graph, &mut nodes, callee);
+                graph.update_edge(from, to, ());
+            }
+        }
+
+        let dot = petgraph::dot::Dot::with_config(&graph, &[petgraph::dot::Config::EdgeNoLabel]);
+        format!("{:?}", dot)
+    }
+
+    /// Returns the crate names participating in each dependency cycle found among
+    /// workspace members (a cycle is any strongly-connected component with >1 node).
+    pub fn detect_cycles(&self) -> Vec<Vec<String>> {
+        petgraph::algo::kosaraju_scc(&self.graph)
+            .into_iter()
+            .filter(|scc| scc.len() > 1)
+            .map(|scc| scc.into_iter().map(|idx| self.graph[idx].clone()).collect())
+            .collect()
+    }
+}
+
+fn call_graph_node(
+    graph: &mut petgraph::graph::DiGraph<String, ()>,
+    nodes: &mut HashMap<String, petgraph::graph::NodeIndex>,
+    name: &str,
+) -> petgraph::graph::NodeIndex {
+    *nodes
+        .entry(name.to_string())
+        .or_insert_with(|| graph.add_node(name.to_string()))
+}
+
+fn collect_manifests(dir: &Path, out: &mut Vec<PathBuf>) -> io::Result<()> {
+    if !dir.is_dir() {
+        return Ok(());
     }
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_dir() {
+            if matches!(path.file_name().and_then(|n| n.to_str()), Some("target" | ".git")) {
+                continue;
+            }
+            collect_manifests(&path, out)?;
+        } else if path.file_name().and_then(|n| n.to_str()) == Some("Cargo.toml") {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
 
-    pub fn get_dependencies(&self, _file: &PathBuf) -> Vec<String> {
-        Vec::new()
+fn index_crate_files(
+    src_dir: &Path,
+    crate_name: &str,
+    out: &mut HashMap<PathBuf, String>,
+    function_calls: &mut HashMap<String, Vec<String>>,
+    imports: &mut HashMap<PathBuf, Vec<String>>,
+) {
+    let Ok(entries) = fs::read_dir(src_dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            index_crate_files(&path, crate_name, out, function_calls, imports);
+        } else if path.extension().and_then(|e| e.to_str()) == Some("rs") {
+            collect_calls_and_imports(&path, function_calls, imports);
+            out.insert(path, crate_name.to_string());
+        }
     }
+}
+
+/// Walks a single file's AST recording, per enclosing function/method, the names
+/// of functions/methods it calls, and the leaf names brought in by its `use`s.
+fn collect_calls_and_imports(
+    path: &Path,
+    function_calls: &mut HashMap<String, Vec<String>>,
+    imports: &mut HashMap<PathBuf, Vec<String>>,
+) {
+    let Ok(content) = fs::read_to_string(path) else {
+        return;
+    };
+    let Ok(syntax) = syn::parse_file(&content) else {
+        return;
+    };
 
-    pub fn get_dependents(&self, _file: &PathBuf) -> Vec<String> {
-        Vec::new()
+    let mut visitor = CallGraphVisitor::default();
+    visitor.visit_file(&syntax);
+
+    for (caller, callees) in visitor.function_calls {
+        function_calls.entry(caller).or_default().extend(callees);
+    }
+    if !visitor.imports.is_empty() {
+        imports.insert(path.to_path_buf(), visitor.imports);
+    }
+}
+
+#[derive(Default)]
+struct CallGraphVisitor {
+    current_fn: Option<String>,
+    function_calls: HashMap<String, Vec<String>>,
+    imports: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for CallGraphVisitor {
+    fn visit_item_fn(&mut self, i: &'ast syn::ItemFn) {
+        let previous = self.current_fn.replace(i.sig.ident.to_string());
+        visit::visit_item_fn(self, i);
+        self.current_fn = previous;
+    }
+
+    fn visit_impl_item_fn(&mut self, i: &'ast syn::ImplItemFn) {
+        let previous = self.current_fn.replace(i.sig.ident.to_string());
+        visit::visit_impl_item_fn(self, i);
+        self.current_fn = previous;
+    }
+
+    fn visit_expr_call(&mut self, i: &'ast syn::ExprCall) {
+        if let syn::Expr::Path(expr_path) = &*i.func {
+            if let Some(segment) = expr_path.path.segments.last() {
+                if let Some(caller) = &self.current_fn {
+                    self.function_calls
+                        .entry(caller.clone())
+                        .or_default()
+                        .push(segment.ident.to_string());
+                }
+            }
+        }
+        visit::visit_expr_call(self, i);
+    }
+
+    fn visit_expr_method_call(&mut self, i: &'ast syn::ExprMethodCall) {
+        if let Some(caller) = &self.current_fn {
+            self.function_calls
+                .entry(caller.clone())
+                .or_default()
+                .push(i.method.to_string());
+        }
+        visit::visit_expr_method_call(self, i);
+    }
+
+    fn visit_item_use(&mut self, i: &'ast syn::ItemUse) {
+        collect_use_names(&i.tree, &mut self.imports);
+        visit::visit_item_use(self, i);
+    }
+}
+
+fn collect_use_names(tree: &syn::UseTree, out: &mut Vec<String>) {
+    match tree {
+        syn::UseTree::Path(p) => collect_use_names(&p.tree, out),
+        syn::UseTree::Name(n) => out.push(n.ident.to_string()),
+        syn::UseTree::Rename(r) => out.push(r.rename.to_string()),
+        syn::UseTree::Glob(_) => {}
+        syn::UseTree::Group(g) => {
+            for item in &g.items {
+                collect_use_names(item, out);
+            }
+        }
     }
 }
 
@@ -66,11 +367,91 @@ impl MetricsAnalyzer {
     pub fn load_file_metrics(_path: &PathBuf, _file: &PathBuf) -> io::Result<FileMetrics> {
         Ok(FileMetrics::default())
     }
+
+    /// Runs complexity, documentation, and security analysis across every path in
+    /// `paths` concurrently via rayon, folding results into a single
+    /// `WorkspaceMetrics` with a lock-free reduction: each thread accumulates its
+    /// own partial totals, which are then combined pairwise (complexity via
+    /// [`ComplexityAccumulator::merge`]) so memory stays flat regardless of
+    /// workspace size.
+    pub fn analyze_workspace(paths: &[PathBuf]) -> WorkspaceMetrics {
+        let detector = language::LanguageDetector::new();
+
+        paths
+            .par_iter()
+            .map(|path| {
+                let content = fs::read_to_string(path).unwrap_or_default();
+
+                let language = detector.detect_language(path, &content);
+                let complexity = complexity::ComplexityAnalyzer::new()
+                    .analyze_file(&content, path, &language, &detector);
+                let mut accumulator = ComplexityAccumulator::default();
+                accumulator.add(complexity.cyclomatic_complexity);
+
+                let doc_lines = content
+                    .lines()
+                    .filter(|line| {
+                        let trimmed = line.trim();
+                        trimmed.starts_with("///") || trimmed.starts_with("//!")
+                    })
+                    .count();
+
+                let findings = security::find_security_issues(path, &content);
+                let security_counts = security::tally(&findings);
+
+                PartialWorkspaceMetrics {
+                    accumulator,
+                    doc_lines,
+                    total_lines: content.lines().count(),
+                    security: security_counts,
+                    files: 1,
+                }
+            })
+            .reduce(PartialWorkspaceMetrics::default, PartialWorkspaceMetrics::merge)
+            .finish()
+    }
 }
 
-#[derive(Debug)]
-pub struct SymbolReference {
-    pub file: PathBuf,
-    pub line: usize,
-    pub context: String,
+#[derive(Default)]
+struct PartialWorkspaceMetrics {
+    accumulator: ComplexityAccumulator,
+    doc_lines: usize,
+    total_lines: usize,
+    security: SecurityFindingCounts,
+    files: usize,
+}
+
+impl PartialWorkspaceMetrics {
+    fn merge(self, other: Self) -> Self {
+        Self {
+            accumulator: self.accumulator.merge(&other.accumulator),
+            doc_lines: self.doc_lines + other.doc_lines,
+            total_lines: self.total_lines + other.total_lines,
+            security: SecurityFindingCounts {
+                critical: self.security.critical + other.security.critical,
+                high: self.security.high + other.security.high,
+                medium: self.security.medium + other.security.medium,
+                low: self.security.low + other.security.low,
+            },
+            files: self.files + other.files,
+        }
+    }
+
+    fn finish(self) -> WorkspaceMetrics {
+        WorkspaceMetrics {
+            files_analyzed: self.files,
+            quality: CodeQualityMetrics {
+                complexity: self.accumulator.finish(),
+                documentation: DocumentationMetrics {
+                    doc_lines: self.doc_lines,
+                    coverage: if self.total_lines > 0 {
+                        self.doc_lines as f64 / self.total_lines as f64
+                    } else {
+                        0.0
+                    },
+                },
+                security: self.security,
+            },
+        }
+    }
 }
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Severity of a [`SecurityFinding`], ordered least to most urgent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Low => "low",
+            Severity::Medium => "medium",
+            Severity::High => "high",
+            Severity::Critical => "critical",
+        }
+    }
+}
+
+/// A single security issue flagged by [`scan_file`], anchored to a file/line/span
+/// so it can be rendered either as a terminal snippet or as a CI annotation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecurityFinding {
+    pub rule_id: String,
+    pub severity: Severity,
+    pub message: String,
+    pub file: std::path::PathBuf,
+    pub line: usize,
+    pub end_line: usize,
+    pub column: usize,
+    pub end_column: usize,
+}
+
+struct Rule {
+    id: &'static str,
+    pattern: &'static str,
+    severity: Severity,
+    message: &'static str,
+}
+
+const RULES: &[Rule] = &[
+    Rule {
+        id: "hardcoded-secret",
+        pattern: "api_key",
+        severity: Severity::Critical,
+        message: "Possible hardcoded API key",
+    },
+    Rule {
+        id: "hardcoded-secret",
+        pattern: "password =",
+        severity: Severity::Critical,
+        message: "Possible hardcoded password",
+    },
+    Rule {
+        id: "unsafe-block",
+        pattern: "unsafe {",
+        severity: Severity::High,
+        message: "Unsafe block bypasses Rust's memory-safety guarantees",
+    },
+    Rule {
+        id: "panicking-unwrap",
+        pattern: ".unwrap()",
+        severity: Severity::Low,
+        message: "Unwrap can panic on unexpected input",
+    },
+    Rule {
+        id: "command-injection",
+        pattern: "Command::new",
+        severity: Severity::Medium,
+        message: "Shelling out can be a command-injection vector if arguments are unsanitized",
+    },
+    Rule {
+        id: "sql-concatenation",
+        pattern: "SELECT",
+        severity: Severity::High,
+        message: "String-built SQL is a possible injection vector; prefer parameterized queries",
+    },
+];
+
+/// Scans `content` line-by-line against a small fixed rule set and returns one
+/// [`SecurityFinding`] per match, anchored at the matching column span.
+pub fn find_security_issues(path: &Path, content: &str) -> Vec<SecurityFinding> {
+    let mut findings = Vec::new();
+
+    for (line_no, line) in content.lines().enumerate() {
+        for rule in RULES {
+            if let Some(col) = line.find(rule.pattern) {
+                findings.push(SecurityFinding {
+                    rule_id: rule.id.to_string(),
+                    severity: rule.severity,
+                    message: rule.message.to_string(),
+                    file: path.to_path_buf(),
+                    line: line_no + 1,
+                    end_line: line_no + 1,
+                    column: col + 1,
+                    end_column: col + 1 + rule.pattern.len(),
+                });
+            }
+        }
+    }
+
+    findings
+}
+
+/// Severity tally used by summary/meta output; kept separate from the raw
+/// finding list so reports can cheaply show counts without cloning findings.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct SecurityFindingCounts {
+    pub critical: usize,
+    pub high: usize,
+    pub medium: usize,
+    pub low: usize,
+}
+
+pub fn tally(findings: &[SecurityFinding]) -> SecurityFindingCounts {
+    let mut counts = SecurityFindingCounts::default();
+    for finding in findings {
+        match finding.severity {
+            Severity::Critical => counts.critical += 1,
+            Severity::High => counts.high += 1,
+            Severity::Medium => counts.medium += 1,
+            Severity::Low => counts.low += 1,
+        }
+    }
+    counts
+}
@@ -1,9 +1,86 @@
 use std::path::Path;
 
-use crate::processor::types::{EnhancedFileStats, FileStatistics};
+use crate::processor::types::{ComplexityMetrics, EnhancedFileStats, FileStatistics};
+
+/// Online (Welford) mean/variance accumulator, so workspace-wide complexity
+/// stats can be folded one file at a time - or merged pairwise across rayon
+/// threads - without ever materializing the full list of complexities.
+#[derive(Debug, Clone, Copy)]
+pub struct ComplexityAccumulator {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for ComplexityAccumulator {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl ComplexityAccumulator {
+    pub fn add(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        let delta2 = value - self.mean;
+        self.m2 += delta * delta2;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+    }
+
+    /// Combines two independently accumulated running stats via the
+    /// parallel-variance merge formula:
+    /// `delta = meanB - meanA; mean = meanA + delta*countB/(countA+countB);
+    /// M2 = M2A + M2B + delta^2 * countA*countB/(countA+countB)`.
+    pub fn merge(&self, other: &Self) -> Self {
+        if self.count == 0 {
+            return *other;
+        }
+        if other.count == 0 {
+            return *self;
+        }
+
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let mean = self.mean + delta * other.count as f64 / count as f64;
+        let m2 = self.m2
+            + other.m2
+            + delta * delta * (self.count as f64 * other.count as f64) / count as f64;
+
+        Self {
+            count,
+            mean,
+            m2,
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    pub fn finish(&self) -> ComplexityMetrics {
+        if self.count == 0 {
+            return ComplexityMetrics::default();
+        }
+        ComplexityMetrics {
+            average: self.mean,
+            maximum: self.max,
+            minimum: self.min,
+            standard_deviation: (self.m2 / self.count as f64).sqrt(),
+        }
+    }
+}
 
 pub struct StatsAnalyzer {
     stats: EnhancedFileStats,
+    complexity_accumulator: ComplexityAccumulator,
 }
 
 impl Default for StatsAnalyzer {
@@ -16,6 +93,7 @@ impl StatsAnalyzer {
     pub fn new() -> Self {
         Self {
             stats: EnhancedFileStats::default(),
+            complexity_accumulator: ComplexityAccumulator::default(),
         }
     }
 
@@ -35,6 +113,9 @@ impl StatsAnalyzer {
         self.stats.total_size += file_stats.size;
         self.stats.total_lines += file_stats.lines;
 
+        self.complexity_accumulator
+            .add(file_stats.complexity.cyclomatic_complexity);
+
         // Update largest files
         self.stats
             .largest_files
@@ -49,36 +130,7 @@ impl StatsAnalyzer {
     }
 
     pub fn calculate_metrics(&mut self) {
-        let complexities: Vec<f64> = self
-            .stats
-            .file_statistics
-            .values()
-            .map(|stats| stats.complexity.cyclomatic_complexity)
-            .collect();
-
-        if !complexities.is_empty() {
-            // Calculate average
-            self.stats.complexity_metrics.average =
-                complexities.iter().sum::<f64>() / complexities.len() as f64;
-
-            // Calculate min/max
-            self.stats.complexity_metrics.maximum = complexities
-                .iter()
-                .fold(f64::NEG_INFINITY, |a, &b| a.max(b));
-            self.stats.complexity_metrics.minimum =
-                complexities.iter().fold(f64::INFINITY, |a, &b| a.min(b));
-
-            // Calculate standard deviation
-            let variance = complexities
-                .iter()
-                .map(|x| {
-                    let diff = x - self.stats.complexity_metrics.average;
-                    diff * diff
-                })
-                .sum::<f64>()
-                / complexities.len() as f64;
-            self.stats.complexity_metrics.standard_deviation = variance.sqrt();
-        }
+        self.stats.complexity_metrics = self.complexity_accumulator.finish();
     }
 
     pub fn get_stats(&self) -> &EnhancedFileStats {
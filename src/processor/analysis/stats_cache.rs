@@ -0,0 +1,139 @@
+use memmap2::Mmap;
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::processor::types::CodeComplexity;
+
+/// Magic bytes identifying a stats-cache file, checked before the archived body is
+/// even touched so a file from an unrelated tool is rejected immediately.
+const CACHE_MAGIC: u32 = 0x5741_4331; // "WAC1"
+/// Bumped whenever `CachedMetrics`/`CachedEntry`'s shape changes; a mismatch forces
+/// a full rebuild rather than risking a misread of incompatible archived bytes.
+const CACHE_VERSION: u32 = 1;
+const HEADER_LEN: usize = 8;
+
+/// The subset of a file's analysis that's actually expensive to recompute - line
+/// classification and complexity walking - as opposed to cheap per-run metadata
+/// (size, timestamps, author) that's refreshed on every scan regardless.
+#[derive(Clone, Serialize, Deserialize, Archive, RkyvSerialize, RkyvDeserialize)]
+#[archive(check_bytes)]
+pub struct CachedMetrics {
+    pub language: String,
+    pub total_lines: usize,
+    pub comment_lines: usize,
+    pub blank_lines: usize,
+    pub code_lines: usize,
+    pub average_line_length: f64,
+    pub max_line_length: usize,
+    pub complexity: CodeComplexity,
+}
+
+/// One cached file's metrics, keyed by path with the `(mtime, size)` pair it was
+/// computed against so a later scan can tell at a glance whether it's still valid.
+#[derive(Clone, Serialize, Deserialize, Archive, RkyvSerialize, RkyvDeserialize)]
+#[archive(check_bytes)]
+pub struct CachedEntry {
+    pub path: PathBuf,
+    pub mtime: u64,
+    pub size: u64,
+    pub metrics: CachedMetrics,
+}
+
+#[derive(Default, Serialize, Deserialize, Archive, RkyvSerialize, RkyvDeserialize)]
+#[archive(check_bytes)]
+struct StatsCacheBody {
+    /// Kept sorted by `path` so a lookup is a binary search over the archived slice
+    /// rather than a linear scan or a full deserialization pass.
+    entries: Vec<CachedEntry>,
+}
+
+/// On-disk, mmap-friendly cache of per-file [`CachedMetrics`]. Loading validates a
+/// small fixed header (magic + version) before handing the rest of the file to
+/// `rkyv::check_archived_root`, so re-scans only pay for parsing the files that
+/// actually changed since the cache was written. This is the "cached FS manifest":
+/// `FileProcessor::analyze_candidate` looks up each candidate by path and compares
+/// the stored `(mtime, size)` pair against the file's current metadata, reusing
+/// `CachedMetrics` instead of recomputing line counts/complexity when they match.
+pub struct StatsCache {
+    entries: Vec<CachedEntry>,
+}
+
+impl Default for StatsCache {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl StatsCache {
+    /// Loads `cache_path`, falling back to an empty cache (forcing a full rebuild)
+    /// if the file is missing, has a mismatched header, or fails archive validation.
+    pub fn load(cache_path: &Path) -> Self {
+        Self::try_load(cache_path).unwrap_or_default()
+    }
+
+    fn try_load(cache_path: &Path) -> io::Result<Self> {
+        let file = fs::File::open(cache_path)?;
+        let mmap = unsafe { Mmap::map(&file)? };
+        if mmap.len() < HEADER_LEN {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "cache header truncated"));
+        }
+
+        let magic = u32::from_le_bytes(mmap[0..4].try_into().unwrap());
+        let version = u32::from_le_bytes(mmap[4..8].try_into().unwrap());
+        if magic != CACHE_MAGIC || version != CACHE_VERSION {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "cache header mismatch"));
+        }
+
+        let archived = rkyv::check_archived_root::<StatsCacheBody>(&mmap[HEADER_LEN..])
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        let body: StatsCacheBody = archived
+            .deserialize(&mut rkyv::Infallible)
+            .expect("Infallible deserializer never fails");
+        Ok(Self { entries: body.entries })
+    }
+
+    /// Returns the cached metrics for `path` if an entry exists and its stored
+    /// `(mtime, size)` still matches - i.e. the file hasn't changed since caching.
+    pub fn get(&self, path: &Path, mtime: u64, size: u64) -> Option<&CachedMetrics> {
+        let idx = self
+            .entries
+            .binary_search_by(|entry| entry.path.as_path().cmp(path))
+            .ok()?;
+        let entry = &self.entries[idx];
+        (entry.mtime == mtime && entry.size == size).then_some(&entry.metrics)
+    }
+
+    /// Writes `entries` to `cache_path` behind the magic/version header. `entries`
+    /// should only contain files seen in the current scan, so files removed from
+    /// the workspace since the last run are pruned automatically.
+    ///
+    /// Written atomically: the new contents go to a sibling temp file first, which is
+    /// then renamed into place, so a crash or a concurrent reader mid-write never
+    /// observes a half-written cache (the old file stays valid until the rename
+    /// completes, instead of `cache_path` being truncated in place).
+    pub fn save(cache_path: &Path, mut entries: Vec<CachedEntry>) -> io::Result<()> {
+        entries.sort_by(|a, b| a.path.cmp(&b.path));
+        let body = StatsCacheBody { entries };
+        let bytes = rkyv::to_bytes::<_, 4096>(&body)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+
+        let mut out = Vec::with_capacity(HEADER_LEN + bytes.len());
+        out.extend_from_slice(&CACHE_MAGIC.to_le_bytes());
+        out.extend_from_slice(&CACHE_VERSION.to_le_bytes());
+        out.extend_from_slice(&bytes);
+
+        let tmp_path = cache_path.with_extension(
+            cache_path
+                .extension()
+                .map(|ext| format!("{}.tmp", ext.to_string_lossy()))
+                .unwrap_or_else(|| "tmp".to_string()),
+        );
+        fs::write(&tmp_path, out)?;
+        fs::rename(&tmp_path, cache_path)
+    }
+}
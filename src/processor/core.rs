@@ -1,23 +1,60 @@
 use chrono::{DateTime, Local};
-use indicatif::{ProgressBar, ProgressStyle};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
 use std::fs::{self, File};
 use std::io::{self, BufWriter, Write};
 use std::path::{Path, PathBuf};
-use std::time::Instant;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
-use crate::config::{Config, OutputType, VerbosityLevel};
-use crate::filters::gitignore::GitignoreFilter;
-use crate::filters::patterns::{should_ignore, should_process_file};
-use crate::output::TreeOutput;
+use crate::config::{AnalysisStrategy, Config, OutputType, VerbosityLevel};
+use crate::filters::file_types::{FileTypeRegistry, TypeFilter};
+use crate::filters::patterns::should_process_file;
+use crate::output::{FilesOutput, TreeOutput};
 use crate::processor::analysis::complexity::ComplexityAnalyzer;
 use crate::processor::analysis::language::LanguageDetector;
+use crate::processor::analysis::security;
 use crate::processor::analysis::stats::StatsAnalyzer;
+use crate::processor::analysis::stats_cache::{CachedEntry, CachedMetrics, StatsCache};
+use crate::processor::analysis::DependencyAnalyzer;
+use crate::processor::log_sink::FileLogSink;
+use crate::processor::output::EnhancedOutputGenerator;
+use crate::processor::progress::ProgressReporter;
 use crate::processor::types::*;
 use crate::processor::visualization::charts::ChartGenerator;
 use crate::processor::visualization::llm::LLMGenerator;
 use crate::processor::visualization::meta::MetaGenerator;
-use crate::processor::visualization::summary::SummaryGenerator;
+use crate::processor::visualization::diagnostics::DiagnosticsReportGenerator;
+use crate::processor::visualization::git::GitReportGenerator;
+use crate::processor::visualization::json_report::{JsonReportFormat, JsonReportGenerator};
+use crate::processor::visualization::security_report::SecurityReportGenerator;
+use crate::processor::visualization::summary::{SummaryFormat, SummaryGenerator};
+
+/// Result of analyzing one candidate file, produced by the parallel scan phase and
+/// folded into `FileProcessor`'s state sequentially afterward.
+enum FileOutcome {
+    Ready {
+        content: String,
+        metadata: fs::Metadata,
+        language: String,
+        complexity: CodeComplexity,
+        metrics: FileMetrics,
+        mtime: u64,
+        size: u64,
+        elapsed: Duration,
+    },
+    /// Content sniffed as binary (NUL byte or a high ratio of non-text bytes in the
+    /// first few KB) and never decoded as UTF-8, so there's no language/complexity to
+    /// report, just metadata.
+    Binary {
+        metadata: fs::Metadata,
+        mtime: u64,
+        size: u64,
+    },
+    AccessError(String),
+}
 
 pub struct FileProcessor {
     // Processing state
@@ -27,24 +64,45 @@ pub struct FileProcessor {
     start_time: Instant,
 
     // Progress tracking
-    progress_bar: ProgressBar,
+    progress: ProgressReporter,
 
     // Configuration
     config: Config,
     exclude_extensions: HashSet<String>,
-    exclude_directories: HashSet<String>,
-    exclude_patterns: HashSet<String>,
     verbose_level: VerbosityLevel,
 
     // File tracking
     processed_files_list: Vec<PathBuf>,
 
-    // Filters
-    gitignore_filter: GitignoreFilter,
+    // Filters - gitignore/overrides/excluded-directory decisions all go through
+    // `Config::is_ignored` now, so only the file-type filters are kept here.
+    file_type_registry: FileTypeRegistry,
+    type_filter: TypeFilter,
+
+    // Per-directory `fs::read_dir` results, memoized so a subtree visited more than
+    // once during a scan (or by a later re-scan in the same run) doesn't hit the
+    // filesystem again. Behind a `Mutex` so the parallel directory walk can share it.
+    dir_listing_cache: Mutex<HashMap<PathBuf, Vec<PathBuf>>>,
+
+    // (device, inode) pairs already counted toward physical disk usage, so hard
+    // links to the same file are only charged for their blocks once.
+    #[cfg(unix)]
+    seen_inodes: HashSet<(u64, u64)>,
 
     // Statistics and Analysis
     file_stats: EnhancedFileStats,
 
+    // Per-file content captured once during analysis, so output generators that
+    // need the raw text (e.g. `write_file_content`) read it from memory instead of
+    // re-reading the file from disk. Left empty in `less-memory` mode.
+    file_contents: HashMap<PathBuf, String>,
+
+    // Incremental stats cache: loaded once at startup, consulted per file to skip
+    // re-analysis of unchanged files, and rewritten with `new_cache_entries` once
+    // the scan completes.
+    stats_cache: StatsCache,
+    new_cache_entries: Vec<CachedEntry>,
+
     // Analysis components
     complexity_analyzer: ComplexityAnalyzer,
     language_detector: LanguageDetector,
@@ -55,6 +113,10 @@ pub struct FileProcessor {
     summary_generator: SummaryGenerator,
     meta_generator: MetaGenerator,
     llm_generator: LLMGenerator,
+    security_report_generator: SecurityReportGenerator,
+
+    // Optional rotating file-logging sink for `log()`, independent of terminal output.
+    log_sink: Option<FileLogSink>,
 }
 
 impl FileProcessor {
@@ -64,53 +126,85 @@ impl FileProcessor {
             processed_files: 0,
             total_size: 0,
             start_time: Instant::now(),
-            progress_bar: ProgressBar::new(0),
+            progress: ProgressReporter::new(0, true, &config.progress_style),
             config: config.clone(),
             exclude_extensions: config.exclude_extensions.clone(),
-            exclude_directories: config.exclude_directories.clone(),
-            exclude_patterns: config.exclude_patterns.clone(),
             verbose_level: config.verbosity.clone(),
             processed_files_list: Vec::new(),
-            gitignore_filter: GitignoreFilter::new(
-                &config.dir_path,
-                config.respect_gitignore,
-                matches!(
-                    config.verbosity,
-                    VerbosityLevel::Debug | VerbosityLevel::Trace
-                ),
-            ),
+            file_type_registry: FileTypeRegistry::with_overrides(&config.type_add),
+            type_filter: TypeFilter::new(&config.type_only, &config.type_not),
+            dir_listing_cache: Mutex::new(HashMap::new()),
+            #[cfg(unix)]
+            seen_inodes: HashSet::new(),
             file_stats: EnhancedFileStats::default(),
+            file_contents: HashMap::new(),
+            stats_cache: match &config.stats_cache_file {
+                Some(path) => StatsCache::load(path),
+                None => StatsCache::default(),
+            },
+            new_cache_entries: Vec::new(),
             complexity_analyzer: ComplexityAnalyzer::new(),
-            language_detector: LanguageDetector::new(),
+            language_detector: match &config.languages_file {
+                Some(path) => LanguageDetector::with_overrides(path),
+                None => LanguageDetector::new(),
+            },
             stats_analyzer: StatsAnalyzer::new(),
             chart_generator: ChartGenerator::new(),
-            summary_generator: SummaryGenerator::new(),
+            summary_generator: SummaryGenerator::with_format(SummaryFormat::parse(
+                &config.output_config.summary_format,
+            )),
             meta_generator: MetaGenerator::new(),
-            llm_generator: LLMGenerator::new(),
+            llm_generator: match &config.token_vocab_file {
+                Some(path) => LLMGenerator::with_vocab(path),
+                None => LLMGenerator::new(),
+            },
+            security_report_generator: SecurityReportGenerator::new(),
+            log_sink: config.log_dir.as_deref().and_then(|dir| {
+                match FileLogSink::new(dir, config.log_max_bytes, config.log_max_files) {
+                    Ok(sink) => Some(sink),
+                    Err(e) => {
+                        eprintln!("⚠️  Failed to open log directory {}: {}", dir.display(), e);
+                        None
+                    }
+                }
+            }),
+        }
+    }
+
+    /// Builds a dedicated rayon pool sized to `config.thread_count`, or `None` to let
+    /// the parallel analysis run on rayon's global (default-sized) pool.
+    fn build_thread_pool(&self) -> Option<rayon::ThreadPool> {
+        let count = self.config.thread_count?;
+        match rayon::ThreadPoolBuilder::new().num_threads(count).build() {
+            Ok(pool) => Some(pool),
+            Err(e) => {
+                self.log(
+                    VerbosityLevel::Warn,
+                    &format!(
+                        "⚠️  Failed to build a {}-thread pool ({}), using the default pool",
+                        count, e
+                    ),
+                );
+                None
+            }
         }
     }
 
     pub fn init(&mut self) -> io::Result<()> {
         self.log(VerbosityLevel::Info, "🔍 Scanning directory...");
         let path_to_scan = self.config.dir_path.clone();
-        self.count_files(&path_to_scan)?;
-
-        if !self.config.quiet {
-            self.progress_bar = ProgressBar::new(self.total_files as u64);
-            let style = match self.config.progress_style.as_str() {
-                "simple" => ProgressStyle::default_bar()
-                    .template("{spinner:.green} [{elapsed_precise}] {pos}/{len}")
-                    .unwrap(),
-                "detailed" => ProgressStyle::default_bar()
-                    .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({percent}%) - {msg}")
-                    .unwrap(),
-                _ => ProgressStyle::default_bar()
-                    .template("{spinner:.green} [{bar:40.cyan/blue}] {pos}/{len}")
-                    .unwrap(),
-            };
-            self.progress_bar.set_style(style);
+        match self.build_thread_pool() {
+            Some(pool) => pool.install(|| self.count_files(&path_to_scan))?,
+            None => self.count_files(&path_to_scan)?,
         }
 
+        self.progress = ProgressReporter::new(
+            self.total_files as u64,
+            self.config.quiet,
+            &self.config.progress_style,
+        );
+        self.progress.start("Starting...");
+
         self.log(
             VerbosityLevel::Info,
             &format!("Found {} files to process", self.total_files),
@@ -128,11 +222,22 @@ impl FileProcessor {
         // Process files
         self.log(VerbosityLevel::Info, "🔄 Processing files...");
         let path_to_process = self.config.dir_path.clone();
-        self.process_directory(&path_to_process)?;
+        match self.build_thread_pool() {
+            Some(pool) => pool.install(|| self.process_directory(&path_to_process))?,
+            None => self.process_directory(&path_to_process)?,
+        }
+        self.save_stats_cache();
 
         // Calculate final metrics
         self.log(VerbosityLevel::Info, "📊 Calculating metrics...");
         self.stats_analyzer.calculate_metrics();
+        let analyzer_stats = self.stats_analyzer.get_stats();
+        self.file_stats.total_lines = analyzer_stats.total_lines;
+        // Binary files already folded their size into `file_stats.total_size` directly
+        // in `apply_file_outcome` (they have no lines/complexity to contribute), so add
+        // rather than overwrite here.
+        self.file_stats.total_size += analyzer_stats.total_size;
+        self.file_stats.complexity_metrics = analyzer_stats.complexity_metrics.clone();
 
         // Generate outputs
         self.log(VerbosityLevel::Info, "📝 Generating outputs...");
@@ -144,14 +249,36 @@ impl FileProcessor {
                 OutputType::Summary => self.generate_summary()?,
                 OutputType::Meta => self.generate_meta()?,
                 OutputType::LLMFormat => self.generate_llm_format()?,
+                OutputType::Security => self.generate_security_report()?,
+                OutputType::Html => self.generate_html_report()?,
+                OutputType::Diagnostics => self.generate_diagnostics_report()?,
+                OutputType::Json => self.generate_json_report()?,
+                OutputType::Git => self.generate_git_report()?,
+                // Bundled last, once every other artifact above is on disk.
+                OutputType::Dist => {}
             }
         }
 
+        if self.config.generated_types.contains(&OutputType::Dist) {
+            self.generate_dist()?;
+        }
+
         // Finish up
         self.finish();
         Ok(())
     }
 
+    /// Number of files folded into the run so far (including binaries, which never
+    /// get a `file_stats` entry of their own).
+    pub fn processed_files(&self) -> usize {
+        self.processed_files
+    }
+
+    /// Cumulative on-disk size of every processed file, in bytes.
+    pub fn total_size(&self) -> u64 {
+        self.total_size
+    }
+
     fn ensure_output_directories(&self) -> io::Result<()> {
         // Create output directory if specified
         if let Some(output_dir) = &self.config.output_config.output_dir {
@@ -181,210 +308,356 @@ impl FileProcessor {
     }
 
     fn should_skip(&self, path: &Path) -> bool {
-        // First check standard ignore patterns
-        if should_ignore(path) {
-            return true;
-        }
+        // `Config::is_ignored` is the single authoritative source here: excluded
+        // directory names, `--exclude-pattern`/`--override` globs (rule-order-last-
+        // wins, so a `!` rule can re-include a path an earlier rule excluded), and
+        // the layered gitignore/.ignore matcher.
+        self.config.is_ignored(path)
+    }
 
-        // Check gitignore patterns
-        if self.gitignore_filter.is_ignored(path) {
-            return true;
-        }
+    /// Walks `dir` for files to process: directory traversal fans out across rayon's
+    /// thread pool (one task per subtree), filtering happens on the cheap directory
+    /// listing alone (name/extension/glob - no `fs::metadata`, no content reads), and
+    /// each qualifying directory's listing is cached so a later lookup of the same
+    /// path (e.g. a re-scan within the same run) doesn't hit the filesystem again.
+    fn collect_candidate_files(&self, dir: &Path) -> Vec<PathBuf> {
+        let entries = self.list_dir(dir);
+
+        let (dirs, files): (Vec<PathBuf>, Vec<PathBuf>) = entries
+            .into_iter()
+            .filter(|path| {
+                let skip = self.should_skip(path);
+                if skip {
+                    self.log(
+                        VerbosityLevel::Debug,
+                        &format!("Skipping: {}", path.display()),
+                    );
+                }
+                !skip
+            })
+            .partition(|path| path.is_dir());
 
-        // Check excluded directories
-        if self.should_skip_directory(path) {
-            return true;
-        }
+        let mut candidates: Vec<PathBuf> = dirs
+            .par_iter()
+            .flat_map(|subdir| self.collect_candidate_files(subdir))
+            .collect();
 
-        // Check custom exclude patterns
-        let path_str = path.to_string_lossy();
-        for pattern in &self.exclude_patterns {
-            if path_str.contains(pattern) {
+        candidates.extend(files.into_iter().filter(|path| {
+            let (should_process, reason) = should_process_file(
+                path,
+                &self.exclude_extensions,
+                &self.file_type_registry,
+                &self.type_filter,
+            );
+            if !should_process {
                 self.log(
                     VerbosityLevel::Debug,
-                    &format!("Skipping matched pattern: {}", path.display()),
+                    &format!("Skipping {}: {}", path.display(), reason),
                 );
-                return true;
             }
-        }
+            should_process
+        }));
 
-        false
+        candidates
     }
 
-    fn process_directory(&mut self, dir: &Path) -> io::Result<()> {
-        for entry in fs::read_dir(dir)? {
-            let entry = entry?;
-            let path = entry.path();
+    /// Returns `dir`'s entries, reading the filesystem only on the first lookup of a
+    /// given directory within this run.
+    fn list_dir(&self, dir: &Path) -> Vec<PathBuf> {
+        if let Some(cached) = self.dir_listing_cache.lock().unwrap().get(dir) {
+            return cached.clone();
+        }
 
-            if self.should_skip(&path) {
-                self.log(
-                    VerbosityLevel::Debug,
-                    &format!("Skipping: {}", path.display()),
-                );
-                continue;
-            }
+        let listing: Vec<PathBuf> = fs::read_dir(dir)
+            .map(|entries| entries.flatten().map(|entry| entry.path()).collect())
+            .unwrap_or_default();
 
-            if path.is_dir() {
-                self.process_directory(&path)?;
-            } else {
-                // Check if file should be processed
-                let (should_process, reason) = should_process_file(&path, &self.exclude_extensions);
+        self.dir_listing_cache
+            .lock()
+            .unwrap()
+            .insert(dir.to_path_buf(), listing.clone());
+        listing
+    }
 
-                if !should_process {
-                    self.log(
-                        VerbosityLevel::Debug,
-                        &format!("Skipping {}: {}", path.display(), reason),
-                    );
-                    continue;
-                }
+    fn process_directory(&mut self, dir: &Path) -> io::Result<()> {
+        let candidates = self.collect_candidate_files(dir);
 
-                // Process the file if it passed all checks
-                match fs::read_to_string(&path) {
-                    Ok(content) => {
-                        if let Err(e) = self.try_process_file(&path, &content) {
-                            self.log_error_with_context(&e, "processing file", &path);
-                            self.file_stats
-                                .processing_errors
-                                .push((path.to_path_buf(), e.to_string()));
-                        }
-                    }
-                    Err(e) => {
-                        self.log_error_with_context(&e, "reading file", &path);
-                        self.file_stats
-                            .access_errors
-                            .push((path.to_path_buf(), e.to_string()));
-                    }
-                }
-            }
+        // The expensive part - reading and analyzing file contents - runs
+        // concurrently; each candidate's outcome is independent of every other's.
+        let outcomes: Vec<FileOutcome> = candidates
+            .par_iter()
+            .map(|path| self.analyze_candidate(path))
+            .collect();
+
+        // Folding back into `file_stats`/`language_stats` happens sequentially, in
+        // the same order regardless of how rayon scheduled the work above, so the
+        // merged result is identical to the serial version run with any thread count.
+        for (path, outcome) in candidates.into_iter().zip(outcomes) {
+            self.apply_file_outcome(&path, outcome);
         }
+
         Ok(())
     }
 
-    fn try_process_file(&mut self, path: &Path, content: &str) -> io::Result<()> {
+    /// Reads and analyzes a single candidate file without touching any processor
+    /// state that isn't safe to share across threads - no mutation, so this can run
+    /// concurrently for every file in a directory tree.
+    fn analyze_candidate(&self, path: &Path) -> FileOutcome {
         let start_time = Instant::now();
 
-        // Get file metadata
         let metadata = match fs::metadata(path) {
             Ok(meta) => meta,
-            Err(e) => {
-                self.log_error_with_context(&e, "reading metadata", path);
-                return Err(e);
+            Err(e) => return FileOutcome::AccessError(e.to_string()),
+        };
+
+        let mtime = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let size = metadata.len();
+
+        match is_binary_file(path) {
+            Ok(true) => {
+                return FileOutcome::Binary {
+                    metadata,
+                    mtime,
+                    size,
+                }
             }
+            Ok(false) => {}
+            Err(e) => return FileOutcome::AccessError(e.to_string()),
+        }
+
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) => return FileOutcome::AccessError(e.to_string()),
         };
 
-        // Update total size
-        self.total_size += metadata.len();
+        let cached = if self.config.stats_cache_file.is_some() {
+            self.stats_cache.get(path, mtime, size).cloned()
+        } else {
+            None
+        };
 
-        // Analyze file complexity
-        let complexity = self.complexity_analyzer.analyze_file(content, path);
+        let (language, complexity, metrics) = match cached {
+            Some(cached) => (
+                cached.language.clone(),
+                cached.complexity.clone(),
+                FileMetrics {
+                    total_lines: cached.total_lines,
+                    code_lines: cached.code_lines,
+                    comment_lines: cached.comment_lines,
+                    blank_lines: cached.blank_lines,
+                    average_line_length: cached.average_line_length,
+                    max_line_length: cached.max_line_length,
+                    total_line_length: 0,
+                },
+            ),
+            None => {
+                // Detect language (drives comment/branch-token-aware analysis below)
+                let language = self.language_detector.detect_language(path, &content);
+
+                // Analyze file complexity using the detected language's comment and
+                // branch-token rules
+                let complexity = self
+                    .complexity_analyzer
+                    .analyze_file(&content, path, &language, &self.language_detector);
+
+                // Calculate file metrics
+                let metrics = self.calculate_file_metrics(&content, &language);
 
-        // Calculate file metrics
-        let metrics = self.calculate_file_metrics(content);
+                (language, complexity, metrics)
+            }
+        };
 
-        // Detect language
-        let language = self.language_detector.detect_language(path, content);
+        FileOutcome::Ready {
+            content,
+            metadata,
+            language,
+            complexity,
+            metrics,
+            mtime,
+            size,
+            elapsed: start_time.elapsed(),
+        }
+    }
+
+    /// Folds one file's analysis result into `file_stats`/the stats cache/progress -
+    /// the only part of per-file processing that touches shared, mutable state.
+    fn apply_file_outcome(&mut self, path: &Path, outcome: FileOutcome) {
+        let (content, metadata, language, complexity, metrics, mtime, size, elapsed) = match outcome {
+            FileOutcome::Ready {
+                content,
+                metadata,
+                language,
+                complexity,
+                metrics,
+                mtime,
+                size,
+                elapsed,
+            } => (content, metadata, language, complexity, metrics, mtime, size, elapsed),
+            FileOutcome::Binary {
+                metadata,
+                mtime: _,
+                size,
+            } => {
+                self.total_size += size;
+                self.file_stats.total_size += size;
+
+                let file_stats = match self.create_binary_file_statistics(path, &metadata) {
+                    Ok(file_stats) => file_stats,
+                    Err(e) => {
+                        self.log_error_with_context(&e, "processing file", path);
+                        self.file_stats
+                            .processing_errors
+                            .push((path.to_path_buf(), e.to_string()));
+                        return;
+                    }
+                };
+                self.file_stats
+                    .file_statistics
+                    .insert(path.to_path_buf(), file_stats);
+                self.prune_file_statistics();
+                return;
+            }
+            FileOutcome::AccessError(message) => {
+                self.log(
+                    VerbosityLevel::Debug,
+                    &format!("Skipping {} (access error): {}", path.display(), message),
+                );
+                self.file_stats
+                    .access_errors
+                    .push((path.to_path_buf(), message));
+                return;
+            }
+        };
+
+        self.total_size += size;
+
+        if self.config.stats_cache_file.is_some() {
+            self.new_cache_entries.push(CachedEntry {
+                path: path.to_path_buf(),
+                mtime,
+                size,
+                metrics: CachedMetrics {
+                    language: language.clone(),
+                    total_lines: metrics.total_lines,
+                    comment_lines: metrics.comment_lines,
+                    blank_lines: metrics.blank_lines,
+                    code_lines: metrics.code_lines,
+                    average_line_length: metrics.average_line_length,
+                    max_line_length: metrics.max_line_length,
+                    complexity: complexity.clone(),
+                },
+            });
+        }
 
         // Create and store file statistics
-        let file_stats = self.create_file_statistics(path, &metrics, complexity.clone())?;
+        let file_stats = match self.create_file_statistics(path, &metadata, &metrics, complexity.clone()) {
+            Ok(file_stats) => file_stats,
+            Err(e) => {
+                self.log_error_with_context(&e, "processing file", path);
+                self.file_stats
+                    .processing_errors
+                    .push((path.to_path_buf(), e.to_string()));
+                return;
+            }
+        };
+        if file_stats.complexity.comment_ratio < self.config.output_config.comment_ratio_threshold {
+            self.file_stats.needs_docs_count += 1;
+        }
+        self.stats_analyzer.update_stats(path, file_stats.clone());
         self.file_stats
             .file_statistics
             .insert(path.to_path_buf(), file_stats);
 
+        // In less-memory mode, keep the map bounded to top-N collections instead of
+        // retaining every file's stats for the life of the run.
+        self.prune_file_statistics();
+
         // Update language statistics
         self.update_language_stats(language, &metrics, &complexity);
 
+        // Security scan
+        self.file_stats
+            .security_findings
+            .extend(security::find_security_issues(path, &content));
+
+        // Cache the content so output generators (e.g. `write_file_content`) read
+        // from memory instead of re-reading the file from disk. Skipped in
+        // `less-memory` mode, where `write_file_content` falls back to a fresh read
+        // rather than holding every file's content for the life of the run.
+        if self.config.analysis_strategy != AnalysisStrategy::LessMemory {
+            self.file_contents.insert(path.to_path_buf(), content);
+        }
+
         // Store processing time
-        let processing_time = start_time.elapsed();
         self.file_stats
             .processing_times
-            .push((path.to_path_buf(), processing_time));
+            .push((path.to_path_buf(), elapsed));
 
         // Update progress
         self.processed_files += 1;
         self.processed_files_list.push(path.to_path_buf());
 
-        if !self.config.quiet {
-            self.progress_bar
-                .set_message(format!("Processing: {}", path.display()));
-            self.progress_bar.inc(1);
-        }
+        self.progress
+            .increment(&format!("Processing: {}", path.display()));
 
         self.log(
             VerbosityLevel::Debug,
             &format!(
                 "Processed {} ({} bytes) in {:?}",
                 path.display(),
-                metadata.len(),
-                processing_time
+                size,
+                elapsed
             ),
         );
-
-        Ok(())
     }
-    fn count_files(&mut self, dir: &Path) -> io::Result<()> {
-        for entry in fs::read_dir(dir)? {
-            let entry = entry?;
-            let path = entry.path();
 
-            if should_ignore(&path) {
-                self.log(
-                    VerbosityLevel::Debug,
-                    &format!("🚫 Ignoring (count): {}", path.display()),
-                );
-                continue;
-            }
-
-            if self.should_skip_directory(&path) {
-                self.log(
-                    VerbosityLevel::Debug,
-                    &format!("Skipping excluded directory (count): {}", path.display()),
-                );
-                continue;
-            }
-
-            if path.is_dir() {
-                self.count_files(&path)?;
-            } else {
-                let (should_process, reason) = should_process_file(&path, &self.exclude_extensions);
-                if should_process {
-                    self.total_files += 1;
-                    if let Ok(metadata) = fs::metadata(&path) {
-                        self.total_size += metadata.len();
-                    }
-                } else {
-                    self.log(
-                        VerbosityLevel::Debug,
-                        &format!("❌ Skipping (count) {}: {}", path.display(), reason),
-                    );
-                }
-            }
+    /// Rewrites the stats cache from `new_cache_entries`, the set of files actually
+    /// seen this scan - so files removed from the workspace since the last run are
+    /// pruned rather than lingering in the cache forever.
+    fn save_stats_cache(&mut self) {
+        let Some(cache_path) = self.config.stats_cache_file.clone() else {
+            return;
+        };
+        let entries = std::mem::take(&mut self.new_cache_entries);
+        if let Err(e) = StatsCache::save(&cache_path, entries) {
+            self.log(
+                VerbosityLevel::Info,
+                &format!("⚠️  Failed to write stats cache {}: {}", cache_path.display(), e),
+            );
         }
-        Ok(())
     }
 
-    fn should_skip_directory(&self, path: &Path) -> bool {
-        if let Some(dir_name) = path.file_name().and_then(|n| n.to_str()) {
-            self.exclude_directories.contains(dir_name)
-        } else {
-            false
-        }
+    /// Collects the same candidate set `process_directory` will later analyze (via
+    /// `collect_candidate_files`, which populates `dir_listing_cache` so the later
+    /// real scan gets a warm cache) and records how many there are for the progress
+    /// bar. Sizes aren't summed here - `total_size` is folded in once per file, in
+    /// `apply_file_outcome`, as each candidate is actually processed.
+    fn count_files(&mut self, dir: &Path) -> io::Result<()> {
+        let candidates = self.collect_candidate_files(dir);
+
+        // `total_size` itself is only folded in once, per file, in
+        // `apply_file_outcome` as each file is actually processed - summing sizes
+        // again here over the same candidates would double it.
+        self.total_files += candidates.len();
+        Ok(())
     }
 
-    fn calculate_file_metrics(&self, content: &str) -> FileMetrics {
+    fn calculate_file_metrics(&self, content: &str, language: &str) -> FileMetrics {
         let mut metrics = FileMetrics::default();
 
+        let counts = self.language_detector.count_lines(language, content);
+        metrics.code_lines = counts.code;
+        metrics.comment_lines = counts.comments;
+        metrics.blank_lines = counts.blanks;
+
         for line in content.lines() {
-            let trimmed = line.trim();
             metrics.total_lines += 1;
 
-            if trimmed.is_empty() {
-                metrics.blank_lines += 1;
-            } else if self.is_comment_line(trimmed) {
-                metrics.comment_lines += 1;
-            } else {
-                metrics.code_lines += 1;
-            }
-
             // Calculate line length statistics
             let line_length = line.len();
             metrics.max_line_length = metrics.max_line_length.max(line_length);
@@ -400,27 +673,73 @@ impl FileProcessor {
         metrics
     }
 
-    fn is_comment_line(&self, line: &str) -> bool {
-        line.starts_with("//")
-            || line.starts_with("#")
-            || line.starts_with("/*")
-            || line.starts_with("*")
-            || line.contains("*/")
-            || line.starts_with("'''")
-            || line.starts_with("\"\"\"")
+    /// How many files each top-N collection keeps in `--strategy less-memory` mode.
+    const BOUNDED_TOP_N: usize = 20;
+
+    /// Bounds `file_stats.file_statistics` to the union of the top-N files by size,
+    /// complexity, and recency, discarding the rest. In `less-time` mode this is a
+    /// no-op; in `less-memory` mode it keeps memory flat regardless of workspace
+    /// size, at the cost of exactness outside those top-N views.
+    fn prune_file_statistics(&mut self) {
+        if self.config.analysis_strategy != AnalysisStrategy::LessMemory {
+            return;
+        }
+
+        // Only pay for the sort-and-filter pass once the map has grown well past
+        // what we intend to keep, rather than on every single insert.
+        if self.file_stats.file_statistics.len() <= Self::BOUNDED_TOP_N * 4 {
+            return;
+        }
+
+        let mut by_size: Vec<&FileStatistics> = self.file_stats.file_statistics.values().collect();
+        by_size.sort_by(|a, b| b.size.cmp(&a.size));
+        let keep_size: HashSet<PathBuf> = by_size
+            .iter()
+            .take(Self::BOUNDED_TOP_N)
+            .map(|s| s.path.clone())
+            .collect();
+
+        let mut by_complexity: Vec<&FileStatistics> =
+            self.file_stats.file_statistics.values().collect();
+        by_complexity.sort_by(|a, b| {
+            b.complexity
+                .cyclomatic_complexity
+                .partial_cmp(&a.complexity.cyclomatic_complexity)
+                .unwrap()
+        });
+        let keep_complexity: HashSet<PathBuf> = by_complexity
+            .iter()
+            .take(Self::BOUNDED_TOP_N)
+            .map(|s| s.path.clone())
+            .collect();
+
+        let mut by_recency: Vec<&FileStatistics> =
+            self.file_stats.file_statistics.values().collect();
+        by_recency.sort_by_key(|s| std::cmp::Reverse(s.last_modified));
+        let keep_recent: HashSet<PathBuf> = by_recency
+            .iter()
+            .take(Self::BOUNDED_TOP_N)
+            .map(|s| s.path.clone())
+            .collect();
+
+        self.file_stats.file_statistics.retain(|path, _| {
+            keep_size.contains(path) || keep_complexity.contains(path) || keep_recent.contains(path)
+        });
     }
 
     fn create_file_statistics(
-        &self,
+        &mut self,
         path: &Path,
+        metadata: &fs::Metadata,
         metrics: &FileMetrics,
         complexity: CodeComplexity,
     ) -> io::Result<FileStatistics> {
-        let metadata = fs::metadata(path)?;
+        let size_on_disk = self.physical_size_for(metadata);
 
         Ok(FileStatistics {
             path: path.to_path_buf(),
             size: metadata.len(),
+            size_on_disk,
             lines: metrics.total_lines,
             comments: metrics.comment_lines,
             blanks: metrics.blank_lines,
@@ -431,9 +750,57 @@ impl FileProcessor {
             commit_count: 0,
             average_line_length: metrics.average_line_length,
             max_line_length: metrics.max_line_length,
+            is_binary: false,
+        })
+    }
+
+    /// Like `create_file_statistics`, but for a file that was sniffed as binary and
+    /// never had its content decoded, so there are no lines/complexity to report.
+    fn create_binary_file_statistics(
+        &mut self,
+        path: &Path,
+        metadata: &fs::Metadata,
+    ) -> io::Result<FileStatistics> {
+        let size_on_disk = self.physical_size_for(metadata);
+
+        Ok(FileStatistics {
+            path: path.to_path_buf(),
+            size: metadata.len(),
+            size_on_disk,
+            lines: 0,
+            comments: 0,
+            blanks: 0,
+            code: 0,
+            complexity: CodeComplexity::default(),
+            last_modified: metadata.modified()?.into(),
+            last_author: String::new(),
+            commit_count: 0,
+            average_line_length: 0.0,
+            max_line_length: 0,
+            is_binary: true,
         })
     }
 
+    /// Returns the bytes this file actually adds to physical disk usage: real
+    /// allocated blocks the first time its (device, inode) pair is seen, `0` for
+    /// every subsequent hard link to an already-counted inode. Falls back to the
+    /// apparent size on platforms without inode metadata.
+    #[cfg(unix)]
+    fn physical_size_for(&mut self, metadata: &fs::Metadata) -> u64 {
+        use std::os::unix::fs::MetadataExt;
+
+        let key = (metadata.dev(), metadata.ino());
+        if !self.seen_inodes.insert(key) {
+            return 0;
+        }
+        metadata.blocks() * 512
+    }
+
+    #[cfg(not(unix))]
+    fn physical_size_for(&mut self, metadata: &fs::Metadata) -> u64 {
+        metadata.len()
+    }
+
     fn update_language_stats(
         &mut self,
         language: String,
@@ -492,42 +859,16 @@ impl FileProcessor {
         // Ensure directory exists
         self.ensure_directory_exists(&output_path)?;
 
-        let file = File::create(&output_path)?;
-        let mut writer = BufWriter::new(file);
-
-        // Write header
-        writeln!(writer, "# Processed Files List")?;
-        writeln!(
-            writer,
-            "# Generated: {}",
-            Local::now().format("%Y-%m-%d %H:%M:%S")
-        )?;
-        writeln!(writer, "# Base Path: {}", self.config.dir_path.display())?;
-        writeln!(writer, "# Total Files: {}", self.processed_files_list.len())?;
-        writeln!(writer)?;
-
-        // Group files by extension
-        let mut files_by_type: HashMap<String, Vec<&Path>> = HashMap::new();
-        for path in &self.processed_files_list {
-            let ext = path
-                .extension()
-                .and_then(|e| e.to_str())
-                .unwrap_or("unknown")
-                .to_string();
-            files_by_type.entry(ext).or_default().push(path);
-        }
-
-        // Write files by type
-        for (ext, files) in files_by_type {
-            writeln!(writer, "\n## {} files", ext.to_uppercase())?;
-            for path in files {
-                if let Ok(relative) = path.strip_prefix(&self.config.dir_path) {
-                    writeln!(writer, "{}", relative.display())?;
-                }
-            }
+        let mut files_output = FilesOutput::new(self.config.dir_path.clone(), self.verbose_level.clone());
+        if self.config.output_config.files_format == "tree" {
+            files_output = files_output.with_tree_mode(
+                self.config.output_config.files_max_depth,
+                self.config.output_config.files_sort_by.clone(),
+                self.config.output_config.files_prune_below,
+            );
         }
+        files_output.generate(&output_path, &self.file_stats)?;
 
-        writer.flush()?;
         self.log(VerbosityLevel::Info, "✅ Files list created successfully");
         Ok(())
     }
@@ -542,11 +883,7 @@ impl FileProcessor {
         // Ensure directory exists
         self.ensure_directory_exists(&output_path)?;
 
-        let tree_output = TreeOutput::new(
-            self.config.dir_path.clone(),
-            self.verbose_level.clone(),
-            self.config.respect_gitignore,
-        );
+        let tree_output = TreeOutput::new(&self.config);
 
         tree_output.generate(&output_path)?;
 
@@ -564,22 +901,25 @@ impl FileProcessor {
         let file = File::create(&output_path)?;
         let mut writer = BufWriter::new(file);
 
-        // Generate language distribution chart
-        let language_data: Vec<(String, f64)> = self
-            .file_stats
-            .language_stats
-            .iter()
-            .map(|(lang, stats)| {
-                let percentage = (stats.lines as f64 / self.file_stats.total_lines as f64) * 100.0;
-                (lang.clone(), percentage)
-            })
-            .collect();
-
-        self.chart_generator.generate_bar_chart(
-            &mut writer,
-            &language_data,
-            "Language Distribution",
-        )?;
+        if self.config.output_config.summary_format == "pretty" {
+            // Generate language distribution chart
+            let language_data: Vec<(String, f64)> = self
+                .file_stats
+                .language_stats
+                .iter()
+                .map(|(lang, stats)| {
+                    let percentage =
+                        (stats.lines as f64 / self.file_stats.total_lines as f64) * 100.0;
+                    (lang.clone(), percentage)
+                })
+                .collect();
+
+            self.chart_generator.generate_bar_chart(
+                &mut writer,
+                &language_data,
+                "Language Distribution",
+            )?;
+        }
 
         self.summary_generator.generate_summary(
             &mut writer,
@@ -660,21 +1000,24 @@ impl FileProcessor {
                 .display()
         )?;
 
-        // Write metadata
-        if let Ok(metadata) = fs::metadata(path) {
-            writeln!(writer, "#### Metadata")?;
-            writeln!(writer, "- Size: {} bytes", metadata.len())?;
-            if let Ok(modified) = metadata.modified() {
-                let datetime: DateTime<Local> = modified.into();
-                writeln!(
-                    writer,
-                    "- Modified: {}",
-                    datetime.format("%Y-%m-%d %H:%M:%S")
-                )?;
-            }
+        // Write metadata, preferring the statistics gathered once during analysis
+        // over a fresh `fs::metadata` call.
+        let is_binary = self
+            .file_stats
+            .file_statistics
+            .get(path)
+            .map(|stats| stats.is_binary)
+            .unwrap_or(false);
 
-            // Add file statistics if available
-            if let Some(stats) = self.file_stats.file_statistics.get(path) {
+        if let Some(stats) = self.file_stats.file_statistics.get(path) {
+            writeln!(writer, "#### Metadata")?;
+            writeln!(writer, "- Size: {} bytes", stats.size)?;
+            writeln!(
+                writer,
+                "- Modified: {}",
+                DateTime::<Local>::from(stats.last_modified).format("%Y-%m-%d %H:%M:%S")
+            )?;
+            if !is_binary {
                 writeln!(writer, "- Lines of Code: {}", stats.code)?;
                 writeln!(writer, "- Comment Lines: {}", stats.comments)?;
                 writeln!(writer, "- Blank Lines: {}", stats.blanks)?;
@@ -686,14 +1029,29 @@ impl FileProcessor {
             }
         }
 
+        if is_binary {
+            let size = self
+                .file_stats
+                .file_statistics
+                .get(path)
+                .map(|stats| stats.size)
+                .unwrap_or(0);
+            writeln!(writer, "\n#### Content")?;
+            writeln!(writer, "[binary file, {} bytes]\n", size)?;
+            writeln!(writer, "---")?;
+            return Ok(());
+        }
+
         // Write file content with language marker
         if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
             writeln!(writer, "\n#### Content")?;
             writeln!(writer, "```{}", ext)?;
-            if let Ok(content) = fs::read_to_string(path) {
-                writeln!(writer, "{}", content)?;
-            } else {
-                writeln!(writer, "// Error: Could not read file content")?;
+            match self.file_contents.get(path) {
+                Some(content) => writeln!(writer, "{}", content)?,
+                None => match fs::read_to_string(path) {
+                    Ok(content) => writeln!(writer, "{}", content)?,
+                    Err(_) => writeln!(writer, "// Error: Could not read file content")?,
+                },
             }
             writeln!(writer, "```\n")?;
         }
@@ -817,6 +1175,18 @@ impl FileProcessor {
                 VerbosityLevel::Trace => println!("TRACE: {}", message),
             }
         }
+
+        // The file sink captures everything regardless of `verbose_level`, so a run
+        // started without `--verbose`/`--quiet` tuned for the terminal can still be
+        // replayed at full Trace/Debug detail from disk afterward.
+        if let Some(sink) = &self.log_sink {
+            sink.write_line(&format!(
+                "[{}] {:?}: {}",
+                Local::now().format("%Y-%m-%d %H:%M:%S%.3f"),
+                level,
+                message
+            ));
+        }
     }
 
     fn log_error_with_context(&self, error: &io::Error, context: &str, path: &Path) {
@@ -827,8 +1197,8 @@ impl FileProcessor {
     }
 
     fn finish(&mut self) {
+        self.progress.finish("Complete!");
         if !self.config.quiet {
-            self.progress_bar.finish_with_message("Complete!");
             let duration = self.start_time.elapsed();
             println!("\n✅ Processing completed:");
             println!("📁 Files processed: {}", self.processed_files);
@@ -876,6 +1246,227 @@ impl FileProcessor {
         )
     }
 
+    fn generate_security_report(&self) -> io::Result<()> {
+        let output_path = self.config.get_output_path(&OutputType::Security);
+        self.log(
+            VerbosityLevel::Info,
+            &format!(
+                "🔒 Creating security report ({}): {}",
+                self.config.output_config.security_format,
+                output_path.display()
+            ),
+        );
+
+        self.ensure_directory_exists(&output_path)?;
+        let file = File::create(&output_path)?;
+        let mut writer = BufWriter::new(file);
+
+        match self.config.output_config.security_format.as_str() {
+            "github" => self
+                .security_report_generator
+                .write_github_annotations(&mut writer, &self.file_stats.security_findings)?,
+            "terminal" => self
+                .security_report_generator
+                .write_terminal_snippets(&mut writer, &self.file_stats.security_findings)?,
+            _ => self
+                .security_report_generator
+                .write_sarif(&mut writer, &self.file_stats.security_findings)?,
+        }
+
+        writer.flush()?;
+        self.log(VerbosityLevel::Info, "✅ Security report created successfully");
+        Ok(())
+    }
+
+    fn generate_diagnostics_report(&self) -> io::Result<()> {
+        let output_path = self.config.get_output_path(&OutputType::Diagnostics);
+        self.log(
+            VerbosityLevel::Info,
+            &format!(
+                "🧪 Creating diagnostics report ({}): {}",
+                self.config.output_config.diagnostics_format,
+                output_path.display()
+            ),
+        );
+
+        self.ensure_directory_exists(&output_path)?;
+        let file = File::create(&output_path)?;
+        let mut writer = BufWriter::new(file);
+
+        let generator = DiagnosticsReportGenerator::new(
+            self.config.output_config.complexity_threshold,
+            self.config.output_config.comment_ratio_threshold,
+        );
+        match self.config.output_config.diagnostics_format.as_str() {
+            "github" => generator.write_github_annotations(&mut writer, &self.file_stats)?,
+            "problem-matcher" => {
+                generator.write_problem_matcher_lines(&mut writer, &self.file_stats)?;
+                writer.flush()?;
+
+                let matcher_path = output_path.with_extension("problem-matcher.json");
+                let matcher_file = File::create(&matcher_path)?;
+                let mut matcher_writer = BufWriter::new(matcher_file);
+                generator.write_problem_matcher(&mut matcher_writer)?;
+                matcher_writer.flush()?;
+                self.log(
+                    VerbosityLevel::Info,
+                    &format!("🧩 Problem matcher written: {}", matcher_path.display()),
+                );
+            }
+            _ => generator.write_sarif(&mut writer, &self.file_stats)?,
+        }
+
+        writer.flush()?;
+        self.log(VerbosityLevel::Info, "✅ Diagnostics report created successfully");
+        Ok(())
+    }
+
+    fn generate_json_report(&self) -> io::Result<()> {
+        let output_path = self.config.get_output_path(&OutputType::Json);
+        self.log(
+            VerbosityLevel::Info,
+            &format!(
+                "🧾 Creating JSON report ({}): {}",
+                self.config.output_config.json_format,
+                output_path.display()
+            ),
+        );
+
+        self.ensure_directory_exists(&output_path)?;
+        let file = File::create(&output_path)?;
+        let mut writer = BufWriter::new(file);
+
+        let format = JsonReportFormat::parse(&self.config.output_config.json_format);
+        let generator = JsonReportGenerator::new(format);
+        generator.generate(&mut writer, &self.file_stats, &self.config, self.start_time.elapsed())?;
+
+        writer.flush()?;
+        self.log(VerbosityLevel::Info, "✅ JSON report created successfully");
+        Ok(())
+    }
+
+    fn generate_git_report(&self) -> io::Result<()> {
+        let output_path = self.config.get_output_path(&OutputType::Git);
+        self.log(
+            VerbosityLevel::Info,
+            &format!("🌿 Creating git status report: {}", output_path.display()),
+        );
+
+        self.ensure_directory_exists(&output_path)?;
+        let file = File::create(&output_path)?;
+        let mut writer = BufWriter::new(file);
+
+        let generator = GitReportGenerator::new();
+        generator.generate(&mut writer, &self.file_stats, &self.config)?;
+
+        writer.flush()?;
+        self.log(VerbosityLevel::Info, "✅ Git status report created successfully");
+        Ok(())
+    }
+
+    fn generate_html_report(&self) -> io::Result<()> {
+        let output_dir = self.config.get_output_path(&OutputType::Html);
+        self.log(
+            VerbosityLevel::Info,
+            &format!("🌐 Creating HTML report: {}", output_dir.display()),
+        );
+
+        let dependency_analyzer = DependencyAnalyzer::scan_workspace(&self.config.dir_path).ok();
+        let dependency_dot = dependency_analyzer.as_ref().map(|d| d.to_dot());
+        let call_graph_dot = dependency_analyzer.as_ref().map(|d| d.call_graph_dot());
+
+        EnhancedOutputGenerator::new(output_dir).generate(
+            &self.file_stats,
+            dependency_dot.as_deref(),
+            call_graph_dot.as_deref(),
+        )?;
+
+        self.log(VerbosityLevel::Info, "✅ HTML report created successfully");
+        Ok(())
+    }
+
+    /// Bundles every `workspace`/`tree`/`summary`/`meta`/`llm` artifact already
+    /// written to the output directory into a single gzip-compressed tarball, with
+    /// paths rooted under a top-level folder named after the scanned directory.
+    /// Filename prefixes that identify each requested output type's artifacts on disk,
+    /// so `generate_dist` bundles whatever was actually asked for via `--generate`
+    /// rather than a fixed, easily-stale whitelist. Excludes `Dist` itself (the archive
+    /// never bundles itself) and `Html` (a directory, not matched by the `is_file` check
+    /// below).
+    fn bundled_prefixes(generated_types: &HashSet<OutputType>) -> Vec<&'static str> {
+        generated_types
+            .iter()
+            .filter_map(|output_type| match output_type {
+                OutputType::Workspace => Some("workspace"),
+                OutputType::Files => Some("files"),
+                OutputType::Tree => Some("tree"),
+                OutputType::Summary => Some("summary"),
+                OutputType::Meta => Some("meta"),
+                OutputType::LLMFormat => Some("llm"),
+                OutputType::Security => Some("security"),
+                OutputType::Diagnostics => Some("diagnostics"),
+                OutputType::Json => Some("report"),
+                OutputType::Git => Some("git"),
+                OutputType::Html | OutputType::Dist => None,
+            })
+            .collect()
+    }
+
+    fn generate_dist(&self) -> io::Result<()> {
+        let archive_path = self.config.get_output_path(&OutputType::Dist);
+        self.log(
+            VerbosityLevel::Info,
+            &format!("📦 Creating dist archive: {}", archive_path.display()),
+        );
+
+        let search_dir = self
+            .config
+            .output_config
+            .output_dir
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("."));
+
+        let archive_root = self
+            .config
+            .dir_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("workspace")
+            .to_string();
+
+        let bundled_prefixes = Self::bundled_prefixes(&self.config.generated_types);
+
+        let tar_gz = File::create(&archive_path)?;
+        let encoder = GzEncoder::new(tar_gz, Compression::default());
+        let mut builder = tar::Builder::new(encoder);
+
+        for entry in fs::read_dir(&search_dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+
+            let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            // The archive itself lives alongside these artifacts; never bundle it.
+            if path == archive_path {
+                continue;
+            }
+
+            if bundled_prefixes.iter().any(|prefix| file_name.starts_with(prefix)) {
+                builder.append_path_with_name(&path, format!("{}/{}", archive_root, file_name))?;
+            }
+        }
+
+        builder.finish()?;
+
+        println!("{}", archive_path.display());
+        self.log(VerbosityLevel::Info, "✅ Dist archive created successfully");
+        Ok(())
+    }
+
     fn generate_llm_format(&self) -> io::Result<()> {
         let output_path = self.config.get_output_path(&OutputType::LLMFormat);
         self.log(
@@ -890,3 +1481,113 @@ impl FileProcessor {
         Ok(())
     }
 }
+
+/// Sniffs whether `path` is binary by reading its first few KB and looking for a NUL
+/// byte or a high ratio of non-text bytes, the same heuristic tools like `file`/`grep
+/// -I` use, rather than fully decoding the file as UTF-8 just to find out it fails.
+fn is_binary_file(path: &Path) -> io::Result<bool> {
+    const SNIFF_LEN: usize = 8192;
+
+    let mut file = File::open(path)?;
+    let mut buf = vec![0u8; SNIFF_LEN];
+    let mut read = 0;
+    loop {
+        match std::io::Read::read(&mut file, &mut buf[read..])? {
+            0 => break,
+            n => read += n,
+        }
+    }
+    let sample = &buf[..read];
+
+    if sample.is_empty() {
+        return Ok(false);
+    }
+    if sample.contains(&0) {
+        return Ok(true);
+    }
+
+    let non_text = sample
+        .iter()
+        .filter(|&&b| b != b'\t' && b != b'\n' && b != b'\r' && !(0x20..=0x7e).contains(&b))
+        .count();
+    Ok(non_text as f64 / sample.len() as f64 > 0.3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `generate_dist`'s bundled-prefix list has to grow whenever a new output type is
+    /// added, or `--generate dist` silently drops it.
+    #[test]
+    fn bundled_prefixes_cover_every_requestable_type() {
+        let all_types: HashSet<OutputType> = [
+            OutputType::Workspace,
+            OutputType::Files,
+            OutputType::Tree,
+            OutputType::Summary,
+            OutputType::Meta,
+            OutputType::LLMFormat,
+            OutputType::Security,
+            OutputType::Html,
+            OutputType::Dist,
+            OutputType::Diagnostics,
+            OutputType::Json,
+            OutputType::Git,
+        ]
+        .into_iter()
+        .collect();
+
+        let prefixes = FileProcessor::bundled_prefixes(&all_types);
+
+        // Every type except `Html` (a directory) and `Dist` (the archive itself)
+        // should contribute a prefix.
+        assert_eq!(prefixes.len(), all_types.len() - 2);
+        assert!(prefixes.contains(&"security"));
+        assert!(prefixes.contains(&"diagnostics"));
+        assert!(prefixes.contains(&"report"));
+        assert!(prefixes.contains(&"git"));
+    }
+
+    /// Regression test for a division-by-zero in `generate_summary()`'s language
+    /// distribution chart: `file_stats.total_lines` has to actually be folded from
+    /// per-file metrics (via `stats_analyzer`), or every language percentage comes
+    /// out as `NaN%`.
+    #[test]
+    fn summary_language_percentages_are_finite() {
+        let test_dir = std::env::temp_dir().join(format!(
+            "workspace_aggregator_test_{}_{}",
+            std::process::id(),
+            "summary_language_percentages_are_finite"
+        ));
+        let _ = fs::remove_dir_all(&test_dir);
+        fs::create_dir_all(&test_dir).unwrap();
+        fs::write(
+            test_dir.join("main.rs"),
+            "fn main() {\n    println!(\"hello\");\n}\n",
+        )
+        .unwrap();
+
+        std::env::set_var(
+            "CARGO_TEST_ARGS",
+            format!(
+                "workspace-aggregator {} --output-dir {} --generate summary",
+                test_dir.display(),
+                test_dir.display()
+            ),
+        );
+
+        let config = Config::new().expect("config should parse");
+        let mut processor = FileProcessor::new(config);
+        processor.process().expect("processing should succeed");
+
+        assert!(processor.file_stats.total_lines > 0);
+        for stats in processor.file_stats.language_stats.values() {
+            let percentage =
+                (stats.lines as f64 / processor.file_stats.total_lines as f64) * 100.0;
+            assert!(percentage.is_finite(), "language percentage was not finite");
+        }
+
+        let _ = fs::remove_dir_all(&test_dir);
+    }
+}
@@ -0,0 +1,118 @@
+use chrono::Local;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Cap a single rotated log file is allowed to reach before a new one is started.
+const DEFAULT_MAX_BYTES: u64 = 4 * 1024 * 1024;
+/// How many rotated files to keep; the oldest beyond this is deleted on rotation.
+const DEFAULT_MAX_FILES: usize = 3;
+/// Re-check the file's real size via `fs::metadata` only after this many writes,
+/// rather than on every call - the in-memory `bytes_written` estimate (~100
+/// bytes/line) is close enough to decide whether a stat is even worth doing.
+const STAT_CHECK_INTERVAL: u64 = 64;
+
+struct FileLogSinkState {
+    dir: PathBuf,
+    max_bytes: u64,
+    max_files: usize,
+    file: File,
+    path: PathBuf,
+    bytes_written: u64,
+    writes_since_check: u64,
+}
+
+/// Optional rotating file-logging backend for `FileProcessor::log`. Writes
+/// timestamped lines to a fresh `workspace-aggregator_<timestamp>.log` file under
+/// `dir`, rolling over to a new file once the current one crosses `max_bytes` and
+/// deleting the oldest rotated files beyond `max_files`. Wrapped in a `Mutex` so it
+/// can be driven from `log`'s `&self` receiver, the same pattern `dir_listing_cache`
+/// uses for shared state behind an otherwise-immutable method.
+pub struct FileLogSink {
+    state: Mutex<FileLogSinkState>,
+}
+
+impl FileLogSink {
+    pub fn new(dir: &Path, max_bytes: Option<u64>, max_files: Option<usize>) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        let max_bytes = max_bytes.unwrap_or(DEFAULT_MAX_BYTES);
+        let max_files = max_files.unwrap_or(DEFAULT_MAX_FILES).max(1);
+        let (file, path) = create_log_file(dir)?;
+
+        Ok(Self {
+            state: Mutex::new(FileLogSinkState {
+                dir: dir.to_path_buf(),
+                max_bytes,
+                max_files,
+                file,
+                path,
+                bytes_written: 0,
+                writes_since_check: 0,
+            }),
+        })
+    }
+
+    /// Appends a single already-formatted log line (without a trailing newline).
+    pub fn write_line(&self, line: &str) {
+        let mut state = match self.state.lock() {
+            Ok(state) => state,
+            Err(_) => return,
+        };
+
+        if let Err(e) = writeln!(state.file, "{}", line) {
+            eprintln!("⚠️  Failed to write to log file: {}", e);
+            return;
+        }
+
+        state.bytes_written += line.len() as u64 + 1;
+        state.writes_since_check += 1;
+
+        if state.writes_since_check >= STAT_CHECK_INTERVAL {
+            state.writes_since_check = 0;
+            let actual = fs::metadata(&state.path)
+                .map(|m| m.len())
+                .unwrap_or(state.bytes_written);
+            if actual >= state.max_bytes {
+                if let Err(e) = rotate(&mut state) {
+                    eprintln!("⚠️  Failed to rotate log file: {}", e);
+                }
+            }
+        }
+    }
+}
+
+fn create_log_file(dir: &Path) -> io::Result<(File, PathBuf)> {
+    let path = dir.join(format!(
+        "workspace-aggregator_{}.log",
+        Local::now().format("%Y%m%d_%H%M%S")
+    ));
+    let file = OpenOptions::new().create(true).append(true).open(&path)?;
+    Ok((file, path))
+}
+
+fn rotate(state: &mut FileLogSinkState) -> io::Result<()> {
+    let (file, path) = create_log_file(&state.dir)?;
+    state.file = file;
+    state.path = path;
+    state.bytes_written = 0;
+
+    let mut existing: Vec<PathBuf> = fs::read_dir(&state.dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .map(|n| n.starts_with("workspace-aggregator_") && n.ends_with(".log"))
+                .unwrap_or(false)
+        })
+        .collect();
+    existing.sort();
+
+    while existing.len() > state.max_files {
+        let oldest = existing.remove(0);
+        let _ = fs::remove_file(&oldest);
+    }
+
+    Ok(())
+}
@@ -1,8 +1,12 @@
 pub mod analysis;
 pub mod core;
+pub mod log_sink;
 pub mod output;
+pub mod progress;
 pub mod types;
 pub mod visualization;
 
 pub use self::core::FileProcessor;
+pub use self::log_sink::FileLogSink;
+pub use self::progress::ProgressReporter;
 pub use self::types::*;
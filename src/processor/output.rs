@@ -1,8 +1,12 @@
-use crate::processor::types::EnhancedFileStats;
+use crate::processor::types::{EnhancedFileStats, FileStatistics};
 use std::fs;
-use std::io;
-use std::path::PathBuf;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
 
+/// Renders an [`EnhancedFileStats`] snapshot into a self-contained static HTML
+/// report: a summary page, one drill-down page per file, and - when Graphviz's
+/// `dot` binary is available - the dependency/call graphs embedded as inline SVG.
 pub struct EnhancedOutputGenerator {
     output_dir: PathBuf,
 }
@@ -12,11 +16,194 @@ impl EnhancedOutputGenerator {
         Self { output_dir }
     }
 
-    pub fn generate(&self, _stats: &EnhancedFileStats) -> io::Result<()> {
-        // Create output directory if it doesn't exist
+    pub fn generate(
+        &self,
+        stats: &EnhancedFileStats,
+        dependency_dot: Option<&str>,
+        call_graph_dot: Option<&str>,
+    ) -> io::Result<()> {
         if !self.output_dir.exists() {
             fs::create_dir_all(&self.output_dir)?;
         }
+
+        let files_dir = self.output_dir.join("files");
+        fs::create_dir_all(&files_dir)?;
+
+        for (path, file_stats) in &stats.file_statistics {
+            let page = render_file_page(path, file_stats);
+            fs::write(files_dir.join(format!("{}.html", file_slug(path))), page)?;
+        }
+
+        let dependency_svg = dependency_dot.and_then(render_svg);
+        let call_graph_svg = call_graph_dot.and_then(render_svg);
+
+        let index = render_index_page(stats, dependency_svg.as_deref(), call_graph_svg.as_deref());
+        fs::write(self.output_dir.join("index.html"), index)?;
+
         Ok(())
     }
 }
+
+/// Shells out to Graphviz's `dot` to turn a DOT document into inline SVG markup,
+/// returning `None` (rather than failing the whole report) when `dot` isn't on PATH.
+fn render_svg(dot: &str) -> Option<String> {
+    let mut child = Command::new("dot")
+        .args(["-Tsvg"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+
+    child.stdin.take()?.write_all(dot.as_bytes()).ok()?;
+    let output = child.wait_with_output().ok()?;
+
+    if output.status.success() {
+        String::from_utf8(output.stdout).ok()
+    } else {
+        None
+    }
+}
+
+fn file_slug(path: &Path) -> String {
+    path.to_string_lossy()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render_index_page(
+    stats: &EnhancedFileStats,
+    dependency_svg: Option<&str>,
+    call_graph_svg: Option<&str>,
+) -> String {
+    let mut languages: Vec<_> = stats.language_stats.iter().collect();
+    languages.sort_by(|a, b| b.1.lines.cmp(&a.1.lines));
+    let mut language_rows = String::new();
+    for (language, lang_stats) in languages {
+        language_rows.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            escape_html(language),
+            lang_stats.files,
+            lang_stats.lines,
+            lang_stats.code_lines,
+            lang_stats.comment_lines
+        ));
+    }
+
+    let mut largest_rows = String::new();
+    for (path, size) in &stats.largest_files {
+        largest_rows.push_str(&format!(
+            "<tr><td><a href=\"files/{}.html\">{}</a></td><td>{}</td></tr>\n",
+            file_slug(path),
+            escape_html(&path.display().to_string()),
+            size
+        ));
+    }
+
+    let dependency_section = dependency_svg
+        .map(|svg| format!("<h2>Dependency Graph</h2>\n<div class=\"graph\">{}</div>", svg))
+        .unwrap_or_default();
+    let call_graph_section = call_graph_svg
+        .map(|svg| format!("<h2>Call Graph</h2>\n<div class=\"graph\">{}</div>", svg))
+        .unwrap_or_default();
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Workspace Report</title>
+<style>
+body {{ font-family: sans-serif; margin: 2rem; }}
+table {{ border-collapse: collapse; margin-bottom: 1.5rem; }}
+th, td {{ border: 1px solid #ccc; padding: 0.4rem 0.8rem; text-align: left; }}
+.graph {{ overflow-x: auto; }}
+</style>
+</head>
+<body>
+<h1>Workspace Report</h1>
+
+<h2>Overview</h2>
+<p>{file_count} files, {total_lines} total lines, {total_size} bytes</p>
+
+<h2>Complexity</h2>
+<table>
+<tr><th>Average</th><th>Minimum</th><th>Maximum</th><th>Std Dev</th></tr>
+<tr><td>{avg:.2}</td><td>{min:.2}</td><td>{max:.2}</td><td>{stddev:.2}</td></tr>
+</table>
+
+<h2>Languages</h2>
+<table>
+<tr><th>Language</th><th>Files</th><th>Lines</th><th>Code</th><th>Comments</th></tr>
+{language_rows}
+</table>
+
+<h2>Largest Files</h2>
+<table>
+<tr><th>Path</th><th>Size (bytes)</th></tr>
+{largest_rows}
+</table>
+
+{dependency_section}
+{call_graph_section}
+</body>
+</html>
+"#,
+        file_count = stats.file_statistics.len(),
+        total_lines = stats.total_lines,
+        total_size = stats.total_size,
+        avg = stats.complexity_metrics.average,
+        min = stats.complexity_metrics.minimum,
+        max = stats.complexity_metrics.maximum,
+        stddev = stats.complexity_metrics.standard_deviation,
+    )
+}
+
+fn render_file_page(path: &Path, file_stats: &FileStatistics) -> String {
+    let display_path = escape_html(&path.display().to_string());
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{display_path}</title>
+<style>
+body {{ font-family: sans-serif; margin: 2rem; }}
+table {{ border-collapse: collapse; }}
+th, td {{ border: 1px solid #ccc; padding: 0.4rem 0.8rem; text-align: left; }}
+</style>
+</head>
+<body>
+<p><a href="../index.html">&larr; Back to summary</a></p>
+<h1>{display_path}</h1>
+<table>
+<tr><th>Size</th><td>{size} bytes</td></tr>
+<tr><th>Lines</th><td>{lines}</td></tr>
+<tr><th>Code</th><td>{code}</td></tr>
+<tr><th>Comments</th><td>{comments}</td></tr>
+<tr><th>Blank</th><td>{blanks}</td></tr>
+<tr><th>Cyclomatic Complexity</th><td>{complexity:.2}</td></tr>
+<tr><th>Last Author</th><td>{author}</td></tr>
+<tr><th>Commits</th><td>{commits}</td></tr>
+</table>
+</body>
+</html>
+"#,
+        size = file_stats.size,
+        lines = file_stats.lines,
+        code = file_stats.code,
+        comments = file_stats.comments,
+        blanks = file_stats.blanks,
+        complexity = file_stats.complexity.cyclomatic_complexity,
+        author = escape_html(&file_stats.last_author),
+        commits = file_stats.commit_count,
+    )
+}
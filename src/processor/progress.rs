@@ -0,0 +1,60 @@
+use indicatif::{ProgressBar, ProgressStyle};
+use std::time::Duration;
+
+/// How often the terminal display is allowed to redraw, so a tight loop over many
+/// small files doesn't spam a non-TTY log with one line per file.
+const REFRESH_RATE: Duration = Duration::from_millis(100);
+
+/// Throttled progress display shared by the stats pass and the workspace content
+/// writer, so both report through one start/increment/finish channel instead of each
+/// rolling their own bar. `quiet` suppresses all output; `style` picks the same
+/// "simple"/"detailed"/bare templates `--progress-style` already accepts.
+pub struct ProgressReporter {
+    bar: Option<ProgressBar>,
+}
+
+impl ProgressReporter {
+    pub fn new(total: u64, quiet: bool, style: &str) -> Self {
+        if quiet {
+            return Self { bar: None };
+        }
+
+        let bar = ProgressBar::new(total);
+        let template = match style {
+            "simple" => ProgressStyle::default_bar()
+                .template("{spinner:.green} [{elapsed_precise}] {pos}/{len}")
+                .unwrap(),
+            "detailed" => ProgressStyle::default_bar()
+                .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({percent}%) - {msg}")
+                .unwrap(),
+            _ => ProgressStyle::default_bar()
+                .template("{spinner:.green} [{bar:40.cyan/blue}] {pos}/{len}")
+                .unwrap(),
+        };
+        bar.set_style(template);
+        bar.enable_steady_tick(REFRESH_RATE);
+
+        Self { bar: Some(bar) }
+    }
+
+    /// Sets the initial status message without advancing the count.
+    pub fn start(&self, message: &str) {
+        if let Some(bar) = &self.bar {
+            bar.set_message(message.to_string());
+        }
+    }
+
+    /// Advances the count by one and updates the status message.
+    pub fn increment(&self, message: &str) {
+        if let Some(bar) = &self.bar {
+            bar.set_message(message.to_string());
+            bar.inc(1);
+        }
+    }
+
+    pub fn finish(&self, message: &str) {
+        if let Some(bar) = &self.bar {
+            bar.finish_with_message(message.to_string());
+        }
+    }
+}
@@ -1,4 +1,6 @@
+use crate::processor::analysis::security::{SecurityFinding, SecurityFindingCounts};
 use chrono::{DateTime, Local, Utc};
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -17,12 +19,22 @@ pub struct EnhancedFileStats {
     pub processing_errors: Vec<(PathBuf, String)>,
     pub output_errors: Vec<(String, String)>,
     pub largest_files: Vec<(PathBuf, u64)>, // Added this field
+    pub security_findings: Vec<SecurityFinding>,
+    /// Running count of processed text files whose `comment_ratio` falls below
+    /// `comment_ratio_threshold`, updated as each file is processed rather than
+    /// recomputed from `file_statistics` - so it stays exact even in
+    /// `AnalysisStrategy::LessMemory`, where `file_statistics` only retains a
+    /// bounded top-N of files and can no longer answer the question on its own.
+    pub needs_docs_count: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileStatistics {
     pub path: PathBuf,
     pub size: u64,
+    /// Bytes this file actually adds to disk usage: real allocated blocks the first
+    /// time its (device, inode) pair is seen, `0` for every subsequent hard link.
+    pub size_on_disk: u64,
     pub lines: usize,
     pub comments: usize,
     pub blanks: usize,
@@ -33,9 +45,14 @@ pub struct FileStatistics {
     pub commit_count: usize,
     pub average_line_length: f64,
     pub max_line_length: usize,
+    /// True for files whose content was sniffed as binary during analysis, so line
+    /// counts/complexity above are all zero rather than meaningful.
+    #[serde(default)]
+    pub is_binary: bool,
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Archive, RkyvSerialize, RkyvDeserialize)]
+#[archive(check_bytes)]
 pub struct LanguageStats {
     pub files: usize,
     pub lines: usize,
@@ -45,7 +62,8 @@ pub struct LanguageStats {
     pub complexity: CodeComplexity,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Archive, RkyvSerialize, RkyvDeserialize)]
+#[archive(check_bytes)]
 pub struct CodeComplexity {
     pub lines_of_code: usize,
     pub cyclomatic_complexity: f64,
@@ -53,6 +71,11 @@ pub struct CodeComplexity {
     pub depth_of_inheritance: usize,
     pub function_count: usize,
     pub class_count: usize,
+    /// Per-function cyclomatic complexity, populated only for languages where
+    /// function spans can be resolved (currently Rust, via `syn`). Empty means no
+    /// per-function breakdown is available and `cyclomatic_complexity` (whole-file)
+    /// should be used instead.
+    pub function_complexities: Vec<f64>,
 }
 
 impl Default for CodeComplexity {
@@ -64,6 +87,7 @@ impl Default for CodeComplexity {
             depth_of_inheritance: 0,
             function_count: 0,
             class_count: 0,
+            function_complexities: Vec::new(),
         }
     }
 }
@@ -96,12 +120,16 @@ pub struct ProjectData {
 pub struct FileData {
     pub path: String,
     pub size: u64,
+    pub size_on_disk: u64,
     pub lines: usize,
     pub comments: usize,
     pub blanks: usize,
     pub code: usize,
     pub complexity: CodeComplexity,
     pub last_modified: DateTime<Utc>,
+    pub average_line_length: f64,
+    pub max_line_length: usize,
+    pub is_binary: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -109,6 +137,36 @@ pub struct AnalysisData {
     pub complexity_metrics: ComplexityMetrics,
     pub total_lines: usize,
     pub total_size: u64,
+    /// Wall-clock time for the whole run, from `FileProcessor::new` to report
+    /// generation - not to be confused with the per-file entries below.
+    pub total_duration_ms: u128,
+    pub processing_times_ms: Vec<(String, u128)>,
+    pub access_errors: Vec<(String, String)>,
+    pub processing_errors: Vec<(String, String)>,
+    pub security_findings: Vec<SecurityFinding>,
+    /// Files ranked by `cyclomatic_complexity`, highest first.
+    pub most_complex_files: Vec<ComplexFileSummary>,
+    /// Files whose `comment_ratio` falls below `comment_ratio_threshold`.
+    pub needs_docs_count: usize,
+    /// Directories ranked by subtree size, heaviest first.
+    pub heaviest_directories: Vec<DirectoryHotspot>,
+    /// Directories ranked by mean subtree cyclomatic complexity, highest first.
+    pub most_complex_directories: Vec<DirectoryHotspot>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ComplexFileSummary {
+    pub path: String,
+    pub cyclomatic_complexity: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DirectoryHotspot {
+    pub path: String,
+    pub total_size: u64,
+    pub file_count: usize,
+    pub mean_cyclomatic_complexity: f64,
+    pub last_modified: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Default)]
@@ -121,3 +179,23 @@ pub struct FileMetrics {
     pub max_line_length: usize,
     pub average_line_length: f64,
 }
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct DocumentationMetrics {
+    pub doc_lines: usize,
+    pub coverage: f64,
+}
+
+/// Workspace-wide quality snapshot produced by `MetricsAnalyzer::analyze_workspace`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CodeQualityMetrics {
+    pub complexity: ComplexityMetrics,
+    pub documentation: DocumentationMetrics,
+    pub security: SecurityFindingCounts,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct WorkspaceMetrics {
+    pub files_analyzed: usize,
+    pub quality: CodeQualityMetrics,
+}
@@ -0,0 +1,188 @@
+use serde_json::{json, Value};
+use std::io::{self, Write};
+
+use crate::processor::types::EnhancedFileStats;
+
+/// Turns accumulated complexity and error data into CI-consumable diagnostics: a
+/// SARIF 2.1.0 document, a stream of GitHub Actions workflow commands, or a plain
+/// `path:line: severity: message` stream paired with a GitHub Actions problem
+/// matcher. Files whose `cyclomatic_complexity` exceeds `complexity_threshold` or
+/// whose `comment_ratio` falls below `comment_ratio_threshold` become `warning`
+/// results; `access_errors`/`processing_errors`/`output_errors` become `error` results.
+pub struct DiagnosticsReportGenerator {
+    complexity_threshold: f64,
+    comment_ratio_threshold: f64,
+}
+
+impl DiagnosticsReportGenerator {
+    pub fn new(complexity_threshold: f64, comment_ratio_threshold: f64) -> Self {
+        Self {
+            complexity_threshold,
+            comment_ratio_threshold,
+        }
+    }
+
+    pub fn write_sarif(&self, writer: &mut impl Write, stats: &EnhancedFileStats) -> io::Result<()> {
+        let mut results = self.complexity_results(stats);
+        results.extend(self.error_results(stats));
+
+        let sarif = json!({
+            "version": "2.1.0",
+            "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "workspace-aggregator",
+                        "version": env!("CARGO_PKG_VERSION"),
+                    }
+                },
+                "results": results,
+            }]
+        });
+
+        serde_json::to_writer_pretty(writer, &sarif)?;
+        Ok(())
+    }
+
+    /// Emits one GitHub Actions workflow command per diagnostic
+    /// (`::warning file=...,line=...::` / `::error file=...,line=...::`).
+    pub fn write_github_annotations(
+        &self,
+        writer: &mut impl Write,
+        stats: &EnhancedFileStats,
+    ) -> io::Result<()> {
+        for (path, file_stat) in &stats.file_statistics {
+            if file_stat.complexity.cyclomatic_complexity > self.complexity_threshold {
+                writeln!(
+                    writer,
+                    "::warning file={},line=1::cyclomatic complexity {:.1} exceeds threshold {:.1} (complexity-threshold)",
+                    path.display(),
+                    file_stat.complexity.cyclomatic_complexity,
+                    self.complexity_threshold
+                )?;
+            }
+        }
+        for (path, message) in &stats.access_errors {
+            writeln!(writer, "::error file={}::{} (access-error)", path.display(), message)?;
+        }
+        for (path, message) in &stats.processing_errors {
+            writeln!(writer, "::error file={}::{} (processing-error)", path.display(), message)?;
+        }
+        for (target, message) in &stats.output_errors {
+            writeln!(writer, "::error ::{}: {} (output-error)", target, message)?;
+        }
+        Ok(())
+    }
+
+    /// Emits one `path:line: severity: message` line per violation, in the form a
+    /// registered GitHub Actions problem matcher (see [`Self::write_problem_matcher`])
+    /// can parse into inline annotations.
+    pub fn write_problem_matcher_lines(
+        &self,
+        writer: &mut impl Write,
+        stats: &EnhancedFileStats,
+    ) -> io::Result<()> {
+        for (path, file_stat) in &stats.file_statistics {
+            if file_stat.complexity.cyclomatic_complexity > self.complexity_threshold {
+                writeln!(
+                    writer,
+                    "{}:1: warning: cyclomatic complexity {:.1} exceeds threshold {:.1}",
+                    path.display(),
+                    file_stat.complexity.cyclomatic_complexity,
+                    self.complexity_threshold
+                )?;
+            }
+            if file_stat.complexity.comment_ratio < self.comment_ratio_threshold {
+                writeln!(
+                    writer,
+                    "{}:1: warning: comment ratio {:.2} below threshold {:.2}",
+                    path.display(),
+                    file_stat.complexity.comment_ratio,
+                    self.comment_ratio_threshold
+                )?;
+            }
+        }
+        for (path, message) in &stats.access_errors {
+            writeln!(writer, "{}:1: error: {}", path.display(), message)?;
+        }
+        for (path, message) in &stats.processing_errors {
+            writeln!(writer, "{}:1: error: {}", path.display(), message)?;
+        }
+        for (target, message) in &stats.output_errors {
+            writeln!(writer, "{}:1: error: {}", target, message)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the companion GitHub Actions problem-matcher JSON describing how to
+    /// parse the lines from [`Self::write_problem_matcher_lines`] back into `file`,
+    /// `line`, `severity`, and `message` fields.
+    pub fn write_problem_matcher(&self, writer: &mut impl Write) -> io::Result<()> {
+        let matcher = json!({
+            "problemMatcher": [{
+                "owner": "workspace-aggregator",
+                "pattern": [{
+                    "regexp": r"^(.+):(\d+): (warning|error): (.+)$",
+                    "file": 1,
+                    "line": 2,
+                    "severity": 3,
+                    "message": 4
+                }]
+            }]
+        });
+        serde_json::to_writer_pretty(writer, &matcher)?;
+        Ok(())
+    }
+
+    fn complexity_results(&self, stats: &EnhancedFileStats) -> Vec<Value> {
+        stats
+            .file_statistics
+            .iter()
+            .filter(|(_, file_stat)| file_stat.complexity.cyclomatic_complexity > self.complexity_threshold)
+            .map(|(path, file_stat)| {
+                json!({
+                    "ruleId": "complexity-threshold",
+                    "level": "warning",
+                    "message": {
+                        "text": format!(
+                            "cyclomatic complexity {:.1} exceeds threshold {:.1}",
+                            file_stat.complexity.cyclomatic_complexity,
+                            self.complexity_threshold
+                        )
+                    },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": path.to_string_lossy() },
+                            "region": { "startLine": 1 }
+                        }
+                    }]
+                })
+            })
+            .collect()
+    }
+
+    fn error_results(&self, stats: &EnhancedFileStats) -> Vec<Value> {
+        let mut results = Vec::new();
+        for (path, message) in &stats.access_errors {
+            results.push(diagnostic_result("access-error", &path.to_string_lossy(), message));
+        }
+        for (path, message) in &stats.processing_errors {
+            results.push(diagnostic_result("processing-error", &path.to_string_lossy(), message));
+        }
+        for (target, message) in &stats.output_errors {
+            results.push(diagnostic_result("output-error", target, message));
+        }
+        results
+    }
+}
+
+fn diagnostic_result(rule_id: &str, uri: &str, message: &str) -> Value {
+    json!({
+        "ruleId": rule_id,
+        "level": "error",
+        "message": { "text": message },
+        "locations": [{
+            "physicalLocation": { "artifactLocation": { "uri": uri } }
+        }]
+    })
+}
@@ -0,0 +1,130 @@
+use git2::{Repository, Status, StatusOptions};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use crate::config::Config;
+use crate::processor::types::EnhancedFileStats;
+
+/// One file's git status, relative to `Config::dir_path`.
+#[derive(Debug, Clone, Serialize)]
+pub struct GitFileStatus {
+    pub path: String,
+    pub status: &'static str,
+}
+
+/// Repository-wide git state - current branch, HEAD short SHA, and per-file
+/// status - the section `MetaGenerator` folds into its own metadata, and the whole
+/// shape of the `OutputType::Git` JSON sidecar.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GitSummary {
+    pub branch: Option<String>,
+    pub head_sha: Option<String>,
+    pub files: Vec<GitFileStatus>,
+}
+
+pub struct GitReportGenerator;
+
+impl Default for GitReportGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GitReportGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn generate(
+        &self,
+        writer: &mut impl Write,
+        stats: &EnhancedFileStats,
+        config: &Config,
+    ) -> io::Result<()> {
+        let summary = collect(config, stats).unwrap_or_default();
+        serde_json::to_writer_pretty(&mut *writer, &summary)?;
+        writeln!(writer)
+    }
+}
+
+/// Opens the repository at `config.dir_path` (or an ancestor of it) and resolves
+/// every processed file's status, `None` if the workspace isn't inside a git
+/// repository at all.
+pub fn collect(config: &Config, stats: &EnhancedFileStats) -> Option<GitSummary> {
+    let repo = Repository::discover(&config.dir_path).ok()?;
+    let workdir = repo.workdir()?.to_path_buf();
+
+    let branch = repo
+        .head()
+        .ok()
+        .and_then(|head| head.shorthand().map(str::to_string));
+    let head_sha = repo
+        .head()
+        .ok()
+        .and_then(|head| head.peel_to_commit().ok())
+        .map(|commit| commit.id().to_string()[..7].to_string());
+
+    let mut status_opts = StatusOptions::new();
+    status_opts
+        .include_untracked(true)
+        .include_ignored(true)
+        .recurse_untracked_dirs(true);
+    let statuses = repo.statuses(Some(&mut status_opts)).ok()?;
+
+    let mut by_path: HashMap<String, &'static str> = HashMap::new();
+    for entry in statuses.iter() {
+        if let Some(path) = entry.path() {
+            by_path.insert(path.to_string(), classify(entry.status()));
+        }
+    }
+
+    let mut paths: Vec<_> = stats.file_statistics.keys().collect();
+    paths.sort();
+    let files = paths
+        .into_iter()
+        .filter_map(|path| {
+            let relative = path.strip_prefix(&workdir).unwrap_or(path);
+            let key = relative.to_string_lossy().replace('\\', "/");
+            by_path.get(key.as_str()).map(|&status| GitFileStatus {
+                path: key,
+                status,
+            })
+        })
+        .collect();
+
+    Some(GitSummary {
+        branch,
+        head_sha,
+        files,
+    })
+}
+
+/// Collapses git2's bitflag `Status` into the single most informative label for a
+/// file, in the priority order the request calls out: conflicted markers first
+/// (they need attention over anything else), then untracked/ignored (no index
+/// entry at all), then renamed, then staged-vs-working-tree changes.
+fn classify(status: Status) -> &'static str {
+    if status.contains(Status::CONFLICTED) {
+        "conflicted"
+    } else if status.contains(Status::WT_NEW) {
+        "untracked"
+    } else if status.contains(Status::IGNORED) {
+        "ignored"
+    } else if status.intersects(Status::INDEX_RENAMED | Status::WT_RENAMED) {
+        "renamed"
+    } else if status.intersects(
+        Status::INDEX_NEW
+            | Status::INDEX_MODIFIED
+            | Status::INDEX_DELETED
+            | Status::INDEX_TYPECHANGE,
+    ) {
+        "staged"
+    } else if status
+        .intersects(Status::WT_MODIFIED | Status::WT_DELETED | Status::WT_TYPECHANGE)
+    {
+        "modified"
+    } else {
+        "clean"
+    }
+}
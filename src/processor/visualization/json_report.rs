@@ -0,0 +1,179 @@
+use chrono::Local;
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crate::config::Config;
+use crate::processor::analysis::{compute_directory_rollups, DirectoryRollup};
+use crate::processor::types::{
+    AnalysisData, ComplexFileSummary, DirectoryHotspot, EnhancedFileStats, FileData, ProjectData,
+    WorkspaceData,
+};
+
+/// How many entries `most_complex_files` carries, matching the summary generator's
+/// own top-5 JSON list.
+const MOST_COMPLEX_LIMIT: usize = 5;
+
+/// Output mode for [`JsonReportGenerator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JsonReportFormat {
+    /// The full analysis snapshot as one pretty-printed JSON object.
+    #[default]
+    Object,
+    /// The same snapshot, but single-line (`serde_json::to_writer`).
+    ObjectCompact,
+    /// One JSON object per file's statistics, newline-delimited, so large repos can
+    /// be streamed/ingested a record at a time instead of parsed whole.
+    Ndjson,
+}
+
+impl JsonReportFormat {
+    pub fn parse(value: &str) -> Self {
+        match value.to_lowercase().as_str() {
+            "ndjson" => JsonReportFormat::Ndjson,
+            "object-compact" | "compact" => JsonReportFormat::ObjectCompact,
+            _ => JsonReportFormat::Object,
+        }
+    }
+}
+
+pub struct JsonReportGenerator {
+    format: JsonReportFormat,
+}
+
+impl JsonReportGenerator {
+    pub fn new(format: JsonReportFormat) -> Self {
+        Self { format }
+    }
+
+    pub fn generate(
+        &self,
+        writer: &mut impl Write,
+        stats: &EnhancedFileStats,
+        config: &Config,
+        duration: Duration,
+    ) -> io::Result<()> {
+        match self.format {
+            JsonReportFormat::Object => {
+                serde_json::to_writer_pretty(
+                    &mut *writer,
+                    &build_workspace_data(stats, config, duration),
+                )?;
+                writeln!(writer)
+            }
+            JsonReportFormat::ObjectCompact => {
+                serde_json::to_writer(&mut *writer, &build_workspace_data(stats, config, duration))?;
+                writeln!(writer)
+            }
+            JsonReportFormat::Ndjson => self.write_ndjson(writer, stats),
+        }
+    }
+
+    fn write_ndjson(&self, writer: &mut impl Write, stats: &EnhancedFileStats) -> io::Result<()> {
+        let mut paths: Vec<_> = stats.file_statistics.keys().collect();
+        paths.sort();
+        for path in paths {
+            let file_data = file_data_for(path, stats);
+            serde_json::to_writer(&mut *writer, &file_data)?;
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+}
+
+fn file_data_for(path: &std::path::Path, stats: &EnhancedFileStats) -> FileData {
+    let file_stats = &stats.file_statistics[path];
+    FileData {
+        path: path.to_string_lossy().to_string(),
+        size: file_stats.size,
+        size_on_disk: file_stats.size_on_disk,
+        lines: file_stats.lines,
+        comments: file_stats.comments,
+        blanks: file_stats.blanks,
+        code: file_stats.code,
+        complexity: file_stats.complexity.clone(),
+        last_modified: file_stats.last_modified,
+        average_line_length: file_stats.average_line_length,
+        max_line_length: file_stats.max_line_length,
+        is_binary: file_stats.is_binary,
+    }
+}
+
+fn build_workspace_data(stats: &EnhancedFileStats, config: &Config, duration: Duration) -> WorkspaceData {
+    let mut paths: Vec<_> = stats.file_statistics.keys().collect();
+    paths.sort();
+    let files = paths
+        .into_iter()
+        .map(|path| file_data_for(path, stats))
+        .collect();
+
+    let stringify = |pairs: &[(std::path::PathBuf, String)]| -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(path, message)| (path.to_string_lossy().to_string(), message.clone()))
+            .collect()
+    };
+
+    let mut complex_files: Vec<_> = stats.file_statistics.iter().collect();
+    complex_files.sort_by(|a, b| {
+        b.1.complexity
+            .cyclomatic_complexity
+            .partial_cmp(&a.1.complexity.cyclomatic_complexity)
+            .unwrap()
+    });
+    let most_complex_files = complex_files
+        .iter()
+        .take(MOST_COMPLEX_LIMIT)
+        .map(|(path, s)| ComplexFileSummary {
+            path: path.to_string_lossy().to_string(),
+            cyclomatic_complexity: s.complexity.cyclomatic_complexity,
+        })
+        .collect();
+
+    let rollups = compute_directory_rollups(stats, &config.dir_path);
+    let to_hotspot = |(path, rollup): (&std::path::PathBuf, &DirectoryRollup)| {
+        DirectoryHotspot {
+            path: path.to_string_lossy().to_string(),
+            total_size: rollup.total_size,
+            file_count: rollup.file_count,
+            mean_cyclomatic_complexity: rollup.mean_complexity(),
+            last_modified: rollup.last_modified,
+        }
+    };
+
+    let mut heaviest: Vec<_> = rollups.iter().collect();
+    heaviest.sort_by(|a, b| b.1.total_size.cmp(&a.1.total_size));
+    let heaviest_directories = heaviest.into_iter().take(MOST_COMPLEX_LIMIT).map(to_hotspot).collect();
+
+    let mut most_complex: Vec<_> = rollups.iter().collect();
+    most_complex.sort_by(|a, b| b.1.mean_complexity().partial_cmp(&a.1.mean_complexity()).unwrap());
+    let most_complex_directories = most_complex.into_iter().take(MOST_COMPLEX_LIMIT).map(to_hotspot).collect();
+
+    WorkspaceData {
+        project: ProjectData {
+            timestamp: Local::now(),
+            base_directory: config.dir_path.to_string_lossy().to_string(),
+            total_files: stats.file_statistics.len(),
+            total_size: stats.total_size,
+            language_stats: stats.language_stats.clone(),
+        },
+        files,
+        analysis: AnalysisData {
+            complexity_metrics: stats.complexity_metrics.clone(),
+            total_lines: stats.total_lines,
+            total_size: stats.total_size,
+            total_duration_ms: duration.as_millis(),
+            processing_times_ms: stats
+                .processing_times
+                .iter()
+                .map(|(path, duration)| (path.to_string_lossy().to_string(), duration.as_millis()))
+                .collect(),
+            access_errors: stringify(&stats.access_errors),
+            processing_errors: stringify(&stats.processing_errors),
+            security_findings: stats.security_findings.clone(),
+            most_complex_files,
+            needs_docs_count: stats.needs_docs_count,
+            heaviest_directories,
+            most_complex_directories,
+        },
+    }
+}
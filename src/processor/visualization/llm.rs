@@ -1,11 +1,15 @@
 use crate::processor::types::*;
+use crate::processor::visualization::token_estimator::TokenEstimator;
 use chrono::Local;
 use std::fs::{self, File};
 use std::io::{self, BufWriter, Write};
 use std::path::Path;
+use syn::spanned::Spanned;
 
 pub struct LLMGenerator {
-    chunk_size: usize,
+    /// Target chunk size, in estimated tokens.
+    token_budget: usize,
+    token_estimator: TokenEstimator,
 }
 
 #[derive(Debug)]
@@ -14,6 +18,7 @@ struct LLMChunk {
     total_chunks: usize,
     content_type: String,
     content: String,
+    token_count: usize,
 }
 
 impl Default for LLMGenerator {
@@ -25,7 +30,18 @@ impl Default for LLMGenerator {
 impl LLMGenerator {
     pub fn new() -> Self {
         Self {
-            chunk_size: 16000, // Approximately 4000 tokens
+            token_budget: 4000,
+            token_estimator: TokenEstimator::new(),
+        }
+    }
+
+    /// Builds an `LLMGenerator` that estimates tokens via a BPE-style merge table
+    /// loaded from `vocab_path`, falling back to the chars/4 approximation if the
+    /// file can't be read.
+    pub fn with_vocab(vocab_path: &Path) -> Self {
+        Self {
+            token_budget: 4000,
+            token_estimator: TokenEstimator::with_vocab(vocab_path),
         }
     }
 
@@ -127,8 +143,6 @@ impl LLMGenerator {
 
     fn generate_chunks(&self, _base_path: &Path, stats: &EnhancedFileStats) -> Vec<LLMChunk> {
         let mut chunks = Vec::new();
-        let mut current_chunk = String::new();
-        let mut current_size = 0;
 
         // Sort files by complexity
         let mut files: Vec<_> = stats.file_statistics.iter().collect();
@@ -139,53 +153,33 @@ impl LLMGenerator {
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
 
-        // Process core files (high complexity)
+        // Process core files (high complexity): each file is broken into
+        // semantic-boundary segments (top-level Rust items, or blank-line-delimited
+        // blocks for other languages) so a chunk split never cuts through the middle
+        // of a function.
+        let mut packer = ChunkPacker::new("core", self.token_budget, &self.token_estimator);
         for (path, stats) in files
             .iter()
             .filter(|(_, s)| s.complexity.cyclomatic_complexity > 10.0)
         {
             if let Ok(content) = fs::read_to_string(path) {
-                let file_content = self.format_file_content(path, stats, &content);
-
-                if current_size + file_content.len() > self.chunk_size && !current_chunk.is_empty()
-                {
-                    chunks.push(self.create_chunk("core", current_chunk));
-                    current_chunk = String::new();
-                    current_size = 0;
+                packer.push_header(&self.format_file_header(path, stats));
+                for segment in split_into_segments(path, &content) {
+                    packer.push_segment(&format!("\n```\n{}\n```\n", segment));
                 }
-
-                current_chunk.push_str(&file_content);
-                current_size += file_content.len();
             }
         }
-
-        if !current_chunk.is_empty() {
-            chunks.push(self.create_chunk("core", current_chunk));
-        }
+        chunks.extend(packer.finish());
 
         // Process supporting files
-        current_chunk = String::new();
-        current_size = 0;
-
+        let mut packer = ChunkPacker::new("supporting", self.token_budget, &self.token_estimator);
         for (path, stats) in files
             .iter()
             .filter(|(_, s)| s.complexity.cyclomatic_complexity <= 10.0)
         {
-            let summary = self.format_file_summary(path, stats);
-
-            if current_size + summary.len() > self.chunk_size && !current_chunk.is_empty() {
-                chunks.push(self.create_chunk("supporting", current_chunk));
-                current_chunk = String::new();
-                current_size = 0;
-            }
-
-            current_chunk.push_str(&summary);
-            current_size += summary.len();
-        }
-
-        if !current_chunk.is_empty() {
-            chunks.push(self.create_chunk("supporting", current_chunk));
+            packer.push_segment(&self.format_file_summary(path, stats));
         }
+        chunks.extend(packer.finish());
 
         // Update sequence numbers
         let total = chunks.len();
@@ -197,14 +191,13 @@ impl LLMGenerator {
         chunks
     }
 
-    fn format_file_content(&self, path: &Path, stats: &FileStatistics, content: &str) -> String {
+    fn format_file_header(&self, path: &Path, stats: &FileStatistics) -> String {
         format!(
-            "\n### File: {}\n#### Metrics\n- Lines: {}\n- Complexity: {:.2}\n- Comments: {}\n\n```\n{}\n```\n",
+            "\n### File: {}\n#### Metrics\n- Lines: {}\n- Complexity: {:.2}\n- Comments: {}\n",
             path.display(),
             stats.lines,
             stats.complexity.cyclomatic_complexity,
-            stats.comments,
-            content
+            stats.comments
         )
     }
 
@@ -218,23 +211,146 @@ impl LLMGenerator {
         )
     }
 
-    fn create_chunk(&self, chunk_type: &str, content: String) -> LLMChunk {
-        LLMChunk {
-            sequence: 0,
-            total_chunks: 0,
-            content_type: chunk_type.to_string(),
-            content,
-        }
-    }
-
     fn write_chunk(&self, writer: &mut impl Write, chunk: &LLMChunk) -> io::Result<()> {
         writeln!(
             writer,
             "# Code Analysis Chunk {}/{}",
             chunk.sequence, chunk.total_chunks
         )?;
-        writeln!(writer, "Type: {}\n", chunk.content_type)?;
+        writeln!(writer, "Type: {}", chunk.content_type)?;
+        writeln!(writer, "Tokens: {}\n", chunk.token_count)?;
         writer.write_all(chunk.content.as_bytes())?;
         Ok(())
     }
 }
+
+/// Packs segments (file headers, code blocks, or summaries) into `LLMChunk`s up to
+/// a token budget, flushing to a new chunk whenever the next segment would exceed
+/// it. A single segment larger than the budget still goes out whole - splitting it
+/// further would mean cutting through a function.
+struct ChunkPacker<'a> {
+    content_type: &'static str,
+    token_budget: usize,
+    estimator: &'a TokenEstimator,
+    chunks: Vec<LLMChunk>,
+    current: String,
+    current_tokens: usize,
+}
+
+impl<'a> ChunkPacker<'a> {
+    fn new(content_type: &'static str, token_budget: usize, estimator: &'a TokenEstimator) -> Self {
+        Self {
+            content_type,
+            token_budget,
+            estimator,
+            chunks: Vec::new(),
+            current: String::new(),
+            current_tokens: 0,
+        }
+    }
+
+    /// A header always starts a file's content; flush first so one file's header
+    /// and its segments don't get separated across chunks more than necessary.
+    fn push_header(&mut self, header: &str) {
+        self.push_segment(header);
+    }
+
+    fn push_segment(&mut self, segment: &str) {
+        let tokens = self.estimator.count_tokens(segment);
+
+        if !self.current.is_empty() && self.current_tokens + tokens > self.token_budget {
+            self.flush();
+        }
+
+        self.current.push_str(segment);
+        self.current_tokens += tokens;
+    }
+
+    fn flush(&mut self) {
+        if self.current.is_empty() {
+            return;
+        }
+        self.chunks.push(LLMChunk {
+            sequence: 0,
+            total_chunks: 0,
+            content_type: self.content_type.to_string(),
+            content: std::mem::take(&mut self.current),
+            token_count: self.current_tokens,
+        });
+        self.current_tokens = 0;
+    }
+
+    fn finish(mut self) -> Vec<LLMChunk> {
+        self.flush();
+        self.chunks
+    }
+}
+
+/// Splits a file's content at semantic boundaries so a later chunk split can't
+/// cut through the middle of an item: top-level item spans for Rust (via `syn`),
+/// or blank-line-delimited blocks for every other language.
+fn split_into_segments(path: &Path, content: &str) -> Vec<String> {
+    if path.extension().and_then(|e| e.to_str()) == Some("rs") {
+        if let Some(segments) = rust_item_segments(content) {
+            if !segments.is_empty() {
+                return segments;
+            }
+        }
+    }
+    split_on_blank_lines(content)
+}
+
+/// Slices `content` along the line ranges of its top-level items, keeping any
+/// preamble (blank lines, free-standing comments) between items as its own
+/// segment. Returns `None` when `content` doesn't parse as valid Rust.
+fn rust_item_segments(content: &str) -> Option<Vec<String>> {
+    let file = syn::parse_file(content).ok()?;
+    let lines: Vec<&str> = content.lines().collect();
+
+    let mut spans: Vec<(usize, usize)> = file
+        .items
+        .iter()
+        .map(|item| {
+            let span = item.span();
+            (span.start().line.saturating_sub(1), span.end().line.min(lines.len()))
+        })
+        .collect();
+    spans.sort_by_key(|&(start, _)| start);
+
+    let mut segments = Vec::new();
+    let mut cursor = 0usize;
+
+    for (start, end) in spans {
+        if start > cursor {
+            segments.push(lines[cursor..start].join("\n"));
+        }
+        let start = start.max(cursor);
+        segments.push(lines[start..end].join("\n"));
+        cursor = end;
+    }
+    if cursor < lines.len() {
+        segments.push(lines[cursor..].join("\n"));
+    }
+
+    Some(segments.into_iter().filter(|s| !s.trim().is_empty()).collect())
+}
+
+/// Splits `content` into blocks separated by blank lines - the closest thing to a
+/// semantic boundary available without a parser for the file's language.
+fn split_on_blank_lines(content: &str) -> Vec<String> {
+    let mut segments = Vec::new();
+    let mut current = String::new();
+
+    for line in content.lines() {
+        if line.trim().is_empty() && !current.is_empty() {
+            segments.push(std::mem::take(&mut current));
+        }
+        current.push_str(line);
+        current.push('\n');
+    }
+    if !current.trim().is_empty() {
+        segments.push(current);
+    }
+
+    segments
+}
@@ -5,6 +5,7 @@ use std::io::{self, Write};
 
 use crate::config::Config;
 use crate::processor::types::EnhancedFileStats;
+use crate::processor::visualization::git;
 
 pub struct MetaGenerator;
 
@@ -31,11 +32,20 @@ impl MetaGenerator {
         // Get total size
         let total_size: u64 = stats.file_statistics.values().map(|s| s.size).sum();
 
-        // Calculate complexity metrics
+        // Calculate complexity metrics. Where per-function spans were resolved
+        // (currently Rust, via `syn`), use each function's own cyclomatic complexity
+        // so the average/stddev reflect functions rather than whole files; otherwise
+        // fall back to the single whole-file number.
         let complexities: Vec<f64> = stats
             .file_statistics
             .values()
-            .map(|s| s.complexity.cyclomatic_complexity)
+            .flat_map(|s| {
+                if s.complexity.function_complexities.is_empty() {
+                    vec![s.complexity.cyclomatic_complexity]
+                } else {
+                    s.complexity.function_complexities.clone()
+                }
+            })
             .collect();
 
         let (avg, max, min, std_dev) = if !complexities.is_empty() {
@@ -80,7 +90,7 @@ impl MetaGenerator {
         });
         largest_files.truncate(10);
 
-        let metadata = json!({
+        let mut metadata = json!({
             "version": env!("CARGO_PKG_VERSION"),
             "timestamp": Local::now().to_rfc3339(),
             "project": {
@@ -124,6 +134,17 @@ impl MetaGenerator {
             }
         });
 
+        // Fold in git status/branch info when the workspace is inside a repo, so a
+        // single `meta` file carries an at-a-glance diff-state view alongside the
+        // rest of the snapshot.
+        if let Some(git_summary) = git::collect(config, stats) {
+            if let Ok(git_value) = serde_json::to_value(&git_summary) {
+                if let Some(object) = metadata.as_object_mut() {
+                    object.insert("git".to_string(), git_value);
+                }
+            }
+        }
+
         serde_json::to_writer_pretty(writer, &metadata)?;
         Ok(())
     }
@@ -1,9 +1,19 @@
 pub mod charts;
+pub mod diagnostics;
+pub mod git;
+pub mod json_report;
 pub mod llm;
 pub mod meta;
+pub mod security_report;
 pub mod summary;
+pub mod token_estimator;
 
 pub use self::charts::ChartGenerator;
+pub use self::diagnostics::DiagnosticsReportGenerator;
+pub use self::git::GitReportGenerator;
+pub use self::json_report::{JsonReportFormat, JsonReportGenerator};
 pub use self::llm::LLMGenerator;
 pub use self::meta::MetaGenerator;
+pub use self::security_report::SecurityReportGenerator;
 pub use self::summary::SummaryGenerator;
+pub use self::token_estimator::TokenEstimator;
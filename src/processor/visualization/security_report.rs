@@ -0,0 +1,175 @@
+use colored::*;
+use serde_json::json;
+use std::fs;
+use std::io::{self, Write};
+
+use crate::processor::analysis::security::{SecurityFinding, Severity};
+
+/// Lines of source shown above/below a finding's span in [`SecurityReportGenerator::write_snippet`].
+const CONTEXT_LINES: usize = 2;
+
+pub struct SecurityReportGenerator;
+
+impl Default for SecurityReportGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SecurityReportGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Serializes findings as a SARIF 2.1.0 document (`runs[].results[]`), the
+    /// format GitHub code scanning and most CI dashboards ingest directly.
+    pub fn write_sarif(
+        &self,
+        writer: &mut impl Write,
+        findings: &[SecurityFinding],
+    ) -> io::Result<()> {
+        let results: Vec<_> = findings
+            .iter()
+            .map(|finding| {
+                json!({
+                    "ruleId": finding.rule_id,
+                    "level": sarif_level(finding.severity),
+                    "message": { "text": finding.message },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": finding.file.to_string_lossy() },
+                            "region": {
+                                "startLine": finding.line,
+                                "endLine": finding.end_line,
+                                "startColumn": finding.column,
+                                "endColumn": finding.end_column,
+                            }
+                        }
+                    }]
+                })
+            })
+            .collect();
+
+        let sarif = json!({
+            "version": "2.1.0",
+            "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "workspace-aggregator",
+                        "version": env!("CARGO_PKG_VERSION"),
+                    }
+                },
+                "results": results,
+            }]
+        });
+
+        serde_json::to_writer_pretty(writer, &sarif)?;
+        Ok(())
+    }
+
+    /// Emits one GitHub Actions workflow command per finding so a CI run can
+    /// annotate the triggering PR directly (`::error file=...,line=...::message`).
+    pub fn write_github_annotations(
+        &self,
+        writer: &mut impl Write,
+        findings: &[SecurityFinding],
+    ) -> io::Result<()> {
+        for finding in findings {
+            let command = match finding.severity {
+                Severity::Critical | Severity::High => "error",
+                Severity::Medium | Severity::Low => "warning",
+            };
+            writeln!(
+                writer,
+                "::{} file={},line={},col={}::{} ({})",
+                command,
+                finding.file.display(),
+                finding.line,
+                finding.column,
+                finding.message,
+                finding.rule_id
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl SecurityReportGenerator {
+    /// Renders every finding in `findings` as a compiler-diagnostic-style snippet:
+    /// a severity-colored message, the offending source line(s) with a line-number
+    /// gutter, and a caret underline beneath the exact column span.
+    pub fn write_terminal_snippets(
+        &self,
+        writer: &mut impl Write,
+        findings: &[SecurityFinding],
+    ) -> io::Result<()> {
+        for finding in findings {
+            self.write_snippet(writer, finding)?;
+        }
+        Ok(())
+    }
+
+    fn write_snippet(&self, writer: &mut impl Write, finding: &SecurityFinding) -> io::Result<()> {
+        let label = format!("{}: {}", finding.severity.as_str(), finding.message);
+        let colored_label = match finding.severity {
+            Severity::Critical | Severity::High => label.red().bold(),
+            Severity::Medium => label.yellow().bold(),
+            Severity::Low => label.blue().bold(),
+        };
+
+        writeln!(
+            writer,
+            "{}\n  --> {}:{}:{}",
+            colored_label,
+            finding.file.display(),
+            finding.line,
+            finding.column
+        )?;
+
+        let Ok(content) = fs::read_to_string(&finding.file) else {
+            writeln!(writer)?;
+            return Ok(());
+        };
+        let lines: Vec<&str> = content.lines().collect();
+
+        let start = finding.line.saturating_sub(1 + CONTEXT_LINES).max(1);
+        let end = (finding.end_line + CONTEXT_LINES).min(lines.len());
+        let gutter_width = end.to_string().len();
+
+        for line_no in start..=end {
+            let Some(text) = lines.get(line_no - 1) else {
+                continue;
+            };
+            writeln!(writer, "{:>width$} | {}", line_no, text, width = gutter_width)?;
+
+            if line_no >= finding.line && line_no <= finding.end_line {
+                let (underline_start, underline_end) = if line_no == finding.line {
+                    (finding.column, finding.end_column)
+                } else {
+                    (1, text.len().max(1))
+                };
+                let caret_len = underline_end.saturating_sub(underline_start).max(1);
+                let caret = "^".repeat(caret_len);
+                writeln!(
+                    writer,
+                    "{:width$} | {}{}",
+                    "",
+                    " ".repeat(underline_start.saturating_sub(1)),
+                    caret.red().bold(),
+                    width = gutter_width
+                )?;
+            }
+        }
+
+        writeln!(writer)
+    }
+}
+
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical | Severity::High => "error",
+        Severity::Medium => "warning",
+        Severity::Low => "note",
+    }
+}
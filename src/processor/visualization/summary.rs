@@ -1,16 +1,54 @@
 use chrono::Local;
 use colored::*;
+use serde_json::json;
 use std::collections::HashMap;
 use std::io::{self, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use terminal_size::{terminal_size, Width};
 
+use crate::processor::analysis::{
+    compute_directory_rollups, DirectoryRollup, DuplicateDetector, DuplicateGroup, GitHistory, Hotspot,
+};
 use crate::processor::types::EnhancedFileStats;
 
+/// Output mode for `SummaryGenerator::generate_summary`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SummaryFormat {
+    #[default]
+    Pretty,
+    Json,
+    JsonCompact,
+}
+
+impl SummaryFormat {
+    pub fn parse(value: &str) -> Self {
+        match value.to_lowercase().as_str() {
+            "json" => SummaryFormat::Json,
+            "json-compact" => SummaryFormat::JsonCompact,
+            _ => SummaryFormat::Pretty,
+        }
+    }
+}
+
+/// Renders a calculated `ProjectMetrics` snapshot into a writer. Implemented once for the
+/// boxed terminal layout and once for serde-serialized output, so both formats are produced
+/// from the exact same data.
+trait SummaryRenderer {
+    fn render(
+        &self,
+        writer: &mut dyn Write,
+        stats: &EnhancedFileStats,
+        base_path: &str,
+        metrics: &ProjectMetrics,
+    ) -> io::Result<()>;
+}
+
 #[derive(Default)]
 struct ProjectMetrics {
     total_files: usize,
     total_size: u64,
+    /// Actual allocated bytes on disk, deduplicated across hard links.
+    size_on_disk: u64,
     total_lines: usize,
     avg_file_size: f64,
     avg_lines_per_file: f64,
@@ -19,11 +57,21 @@ struct ProjectMetrics {
     code_to_comment_ratio: f64,
     complexity_distribution: HashMap<String, usize>, // Complexity buckets
     language_distribution: HashMap<String, usize>,   // Language stats
+    duplicate_groups: Vec<DuplicateGroup>,
+    /// Commit-log data for the scanned directory, or `None` outside a git repository.
+    git_history: Option<GitHistory>,
+    /// Files that are both frequently changed and complex, per `git_history`.
+    hotspots: Vec<Hotspot>,
+    /// Directories ranked by subtree size, heaviest first.
+    heaviest_directories: Vec<(PathBuf, DirectoryRollup)>,
+    /// Directories ranked by mean subtree cyclomatic complexity, highest first.
+    most_complex_directories: Vec<(PathBuf, DirectoryRollup)>,
 }
 
 pub struct SummaryGenerator {
     width: usize,
     use_color: bool,
+    format: SummaryFormat,
 }
 
 impl Default for SummaryGenerator {
@@ -41,6 +89,14 @@ impl SummaryGenerator {
         Self {
             width,
             use_color: true,
+            format: SummaryFormat::default(),
+        }
+    }
+
+    pub fn with_format(format: SummaryFormat) -> Self {
+        Self {
+            format,
+            ..Self::new()
         }
     }
 
@@ -50,30 +106,35 @@ impl SummaryGenerator {
         stats: &EnhancedFileStats,
         base_path: &str,
     ) -> io::Result<()> {
-        let metrics = self.calculate_project_metrics(stats);
-
-        self.write_header(writer)?;
-        self.write_project_info(writer, base_path)?;
-        self.write_key_metrics(writer, stats, &metrics)?;
-        self.write_language_breakdown(writer, stats, &metrics)?;
-        self.write_complexity_analysis(writer, stats, &metrics)?;
-        self.write_file_insights(writer, stats, &metrics)?;
-        self.write_recommendations(writer, stats, &metrics)?;
-        self.write_footer(writer)?;
-        Ok(())
+        let metrics = self.calculate_project_metrics(stats, base_path);
+
+        match self.format {
+            SummaryFormat::Pretty => {
+                PrettyRenderer { generator: self }.render(writer, stats, base_path, &metrics)
+            }
+            SummaryFormat::Json => {
+                JsonRenderer { compact: false }.render(writer, stats, base_path, &metrics)
+            }
+            SummaryFormat::JsonCompact => {
+                JsonRenderer { compact: true }.render(writer, stats, base_path, &metrics)
+            }
+        }
     }
 
-    fn calculate_project_metrics(&self, stats: &EnhancedFileStats) -> ProjectMetrics {
+    fn calculate_project_metrics(&self, stats: &EnhancedFileStats, base_path: &str) -> ProjectMetrics {
         let mut metrics = ProjectMetrics::default();
 
-        // Basic metrics
+        // Basic metrics. Apparent and on-disk size are summed directly from
+        // `file_statistics` rather than trusted from `stats.total_size`, which hard
+        // links would otherwise cause to double-count.
         metrics.total_files = stats.file_statistics.len();
-        metrics.total_size = stats.total_size;
+        metrics.total_size = stats.file_statistics.values().map(|s| s.size).sum();
+        metrics.size_on_disk = stats.file_statistics.values().map(|s| s.size_on_disk).sum();
         metrics.total_lines = stats.total_lines;
 
         // Calculate averages
         if metrics.total_files > 0 {
-            metrics.avg_file_size = stats.total_size as f64 / metrics.total_files as f64;
+            metrics.avg_file_size = metrics.total_size as f64 / metrics.total_files as f64;
             metrics.avg_lines_per_file = stats.total_lines as f64 / metrics.total_files as f64;
         }
 
@@ -95,10 +156,49 @@ impl SummaryGenerator {
                 .insert(lang.clone(), stats.files);
         }
 
+        metrics.duplicate_groups = DuplicateDetector::find_duplicates(&stats.file_statistics);
+
+        let git_history = GitHistory::collect(Path::new(base_path));
+        if let Some(git_history) = &git_history {
+            let mut hotspots: Vec<Hotspot> = stats
+                .file_statistics
+                .iter()
+                .filter_map(|(path, file_stat)| {
+                    let churn = git_history.churn_for(path)?;
+                    if churn.commit_count > 1 && file_stat.complexity.cyclomatic_complexity > 10.0 {
+                        Some(Hotspot {
+                            path: path.clone(),
+                            complexity: file_stat.complexity.cyclomatic_complexity,
+                            commits: churn.commit_count,
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            hotspots.sort_by(|a, b| {
+                (b.complexity * b.commits as f64)
+                    .partial_cmp(&(a.complexity * a.commits as f64))
+                    .unwrap()
+            });
+            metrics.hotspots = hotspots;
+        }
+        metrics.git_history = git_history;
+
+        let rollups = compute_directory_rollups(stats, Path::new(base_path));
+
+        let mut heaviest: Vec<_> = rollups.clone().into_iter().collect();
+        heaviest.sort_by(|a, b| b.1.total_size.cmp(&a.1.total_size));
+        metrics.heaviest_directories = heaviest.into_iter().take(5).collect();
+
+        let mut most_complex: Vec<_> = rollups.into_iter().collect();
+        most_complex.sort_by(|a, b| b.1.mean_complexity().partial_cmp(&a.1.mean_complexity()).unwrap());
+        metrics.most_complex_directories = most_complex.into_iter().take(5).collect();
+
         metrics
     }
 
-    fn write_header(&self, writer: &mut impl Write) -> io::Result<()> {
+    fn write_header(&self, writer: &mut dyn Write) -> io::Result<()> {
         let separator = "=".repeat(self.width);
         writeln!(writer, "\n{}", separator.blue())?;
         writeln!(
@@ -117,7 +217,7 @@ impl SummaryGenerator {
         Ok(())
     }
 
-    fn write_project_info(&self, writer: &mut impl Write, base_path: &str) -> io::Result<()> {
+    fn write_project_info(&self, writer: &mut dyn Write, base_path: &str) -> io::Result<()> {
         writeln!(writer, "{}", "📁 Project Location".bold())?;
         writeln!(writer, "{}", "-".repeat(40))?;
         writeln!(writer, "Base Path: {}", base_path)?;
@@ -127,7 +227,7 @@ impl SummaryGenerator {
 
     fn write_key_metrics(
         &self,
-        writer: &mut impl Write,
+        writer: &mut dyn Write,
         stats: &EnhancedFileStats,
         metrics: &ProjectMetrics,
     ) -> io::Result<()> {
@@ -139,9 +239,14 @@ impl SummaryGenerator {
         writeln!(writer, "  Total Files:        {:>8}", metrics.total_files)?;
         writeln!(
             writer,
-            "  Total Size:         {:>8.2} MB",
+            "  Apparent Size:      {:>8.2} MB",
             metrics.total_size as f64 / (1024.0 * 1024.0)
         )?;
+        writeln!(
+            writer,
+            "  Size on Disk:       {:>8.2} MB",
+            metrics.size_on_disk as f64 / (1024.0 * 1024.0)
+        )?;
         writeln!(
             writer,
             "  Average File Size:  {:>8.2} KB",
@@ -186,7 +291,7 @@ impl SummaryGenerator {
 
     fn write_language_breakdown(
         &self,
-        writer: &mut impl Write,
+        writer: &mut dyn Write,
         stats: &EnhancedFileStats,
         _metrics: &ProjectMetrics,
     ) -> io::Result<()> {
@@ -219,7 +324,7 @@ impl SummaryGenerator {
 
     fn write_complexity_analysis(
         &self,
-        writer: &mut impl Write,
+        writer: &mut dyn Write,
         stats: &EnhancedFileStats,
         metrics: &ProjectMetrics,
     ) -> io::Result<()> {
@@ -259,18 +364,64 @@ impl SummaryGenerator {
         }
         writeln!(writer)?;
 
+        // Directory-level rollups, so hotspots are visible at folder granularity
+        // too, not just the flat top-5 files above.
+        writeln!(writer, "{}", "Heaviest Directories:".yellow())?;
+        for (path, rollup) in &metrics.heaviest_directories {
+            writeln!(
+                writer,
+                "  {:>8.2} MB - {}",
+                rollup.total_size as f64 / (1024.0 * 1024.0),
+                path.display()
+            )?;
+        }
+        writeln!(writer)?;
+
+        writeln!(writer, "{}", "Most Complex Directories:".yellow())?;
+        for (path, rollup) in &metrics.most_complex_directories {
+            writeln!(
+                writer,
+                "  {:.1} - {}",
+                rollup.mean_complexity(),
+                path.display()
+            )?;
+        }
+        writeln!(writer)?;
+
         Ok(())
     }
 
     fn write_file_insights(
         &self,
-        writer: &mut impl Write,
+        writer: &mut dyn Write,
         stats: &EnhancedFileStats,
-        _metrics: &ProjectMetrics,
+        metrics: &ProjectMetrics,
     ) -> io::Result<()> {
         writeln!(writer, "{}", "💡 File Insights".bold())?;
         writeln!(writer, "{}", "-".repeat(40))?;
 
+        // Duplicate files
+        if !metrics.duplicate_groups.is_empty() {
+            writeln!(writer, "{}", "Duplicate Files:".yellow())?;
+            for group in metrics.duplicate_groups.iter().take(5) {
+                writeln!(
+                    writer,
+                    "  {:>8.2} MB wasted - {} copies of {}",
+                    group.wasted_bytes() as f64 / (1024.0 * 1024.0),
+                    group.paths.len(),
+                    group.paths[0].display()
+                )?;
+            }
+            let reclaimable: u64 = metrics.duplicate_groups.iter().map(|g| g.wasted_bytes()).sum();
+            writeln!(
+                writer,
+                "  Total reclaimable: {:.2} MB across {} duplicate sets",
+                reclaimable as f64 / (1024.0 * 1024.0),
+                metrics.duplicate_groups.len()
+            )?;
+            writeln!(writer)?;
+        }
+
         // Largest files
         writeln!(writer, "{}", "Largest Files:".yellow())?;
         let mut largest: Vec<_> = stats.file_statistics.iter().collect();
@@ -286,21 +437,70 @@ impl SummaryGenerator {
         }
         writeln!(writer)?;
 
-        // Time-based analysis
+        // Time-based analysis: git history is a more meaningful "recent" signal than
+        // filesystem mtimes, which are meaningless after a fresh clone.
         writeln!(writer, "{}", "Recent Changes:".yellow())?;
-        let mut recent_files: Vec<_> = stats.file_statistics.iter().collect();
-        recent_files.sort_by_key(|(_, stats)| std::cmp::Reverse(stats.last_modified));
+        if let Some(git_history) = &metrics.git_history {
+            let mut recent: Vec<_> = stats
+                .file_statistics
+                .keys()
+                .filter_map(|path| {
+                    let churn = git_history.churn_for(path)?;
+                    churn.last_commit.map(|date| (path, date))
+                })
+                .collect();
+            recent.sort_by_key(|(_, date)| std::cmp::Reverse(*date));
+
+            for (path, date) in recent.iter().take(5) {
+                writeln!(writer, "  {} - {}", date.format("%Y-%m-%d %H:%M:%S"), path.display())?;
+            }
+        } else {
+            let mut recent_files: Vec<_> = stats.file_statistics.iter().collect();
+            recent_files.sort_by_key(|(_, stats)| std::cmp::Reverse(stats.last_modified));
 
-        for (path, stats) in recent_files.iter().take(5) {
-            writeln!(
-                writer,
-                "  {} - {}",
-                stats.last_modified.format("%Y-%m-%d %H:%M:%S"),
-                path.display()
-            )?;
+            for (path, stats) in recent_files.iter().take(5) {
+                writeln!(
+                    writer,
+                    "  {} - {}",
+                    stats.last_modified.format("%Y-%m-%d %H:%M:%S"),
+                    path.display()
+                )?;
+            }
         }
         writeln!(writer)?;
 
+        // Git-backed churn and authorship, when the scanned directory is inside a repo.
+        if let Some(git_history) = &metrics.git_history {
+            writeln!(writer, "{}", "Git History:".yellow())?;
+            writeln!(writer, "  Total Commits: {:>8}", git_history.total_commits)?;
+            if let Some(last_commit) = git_history.last_commit_date {
+                writeln!(
+                    writer,
+                    "  Last Commit:   {:>8}",
+                    last_commit.format("%Y-%m-%d %H:%M:%S")
+                )?;
+            }
+            writeln!(writer, "  Top Authors:")?;
+            for (author, commits) in &git_history.top_authors {
+                writeln!(writer, "    {:<24} {:>4} commits", author, commits)?;
+            }
+            writeln!(writer)?;
+
+            if !metrics.hotspots.is_empty() {
+                writeln!(writer, "{}", "Refactor Hotspots (high churn + high complexity):".yellow())?;
+                for hotspot in metrics.hotspots.iter().take(5) {
+                    writeln!(
+                        writer,
+                        "  {:.1} complexity, {} commits - {}",
+                        hotspot.complexity,
+                        hotspot.commits,
+                        hotspot.path.display()
+                    )?;
+                }
+                writeln!(writer)?;
+            }
+        }
+
         // File extensions
         let mut extension_stats: HashMap<String, (usize, u64)> = HashMap::new();
         for (path, size) in &stats.largest_files {
@@ -361,61 +561,19 @@ impl SummaryGenerator {
 
     fn write_recommendations(
         &self,
-        writer: &mut impl Write,
+        writer: &mut dyn Write,
         stats: &EnhancedFileStats,
-        _metrics: &ProjectMetrics,
+        metrics: &ProjectMetrics,
     ) -> io::Result<()> {
         writeln!(writer, "{}", "💡 Recommendations".bold())?;
         writeln!(writer, "{}", "-".repeat(40))?;
 
-        let mut recommendations = Vec::new();
-
-        // Complexity recommendations
-        let complex_files = stats
-            .file_statistics
-            .iter()
-            .filter(|(_, s)| s.complexity.cyclomatic_complexity > 20.0)
-            .count();
-
-        if complex_files > 0 {
-            recommendations.push(format!(
-                "• Consider refactoring {} files with high complexity",
-                complex_files
-            ));
-        }
-
-        // Documentation recommendations
-        let poorly_documented = stats
-            .file_statistics
-            .iter()
-            .filter(|(_, s)| (s.comments as f64) / (s.lines as f64) < 0.1)
-            .count();
-
-        if poorly_documented > 0 {
-            recommendations.push(format!(
-                "• Add documentation to {} files with low comment coverage",
-                poorly_documented
-            ));
-        }
-
-        // File size recommendations
-        let large_files = stats
-            .file_statistics
-            .iter()
-            .filter(|(_, s)| s.size > 100 * 1024) // Files larger than 100KB
-            .count();
-
-        if large_files > 0 {
-            recommendations.push(format!(
-                "• Consider splitting {} large files (>100KB)",
-                large_files
-            ));
-        }
+        let recommendations = build_recommendations(stats, metrics);
 
         if recommendations.is_empty() {
             writeln!(writer, "✅ No immediate improvements needed")?;
         } else {
-            for rec in recommendations {
+            for rec in &recommendations {
                 writeln!(writer, "{}", rec)?;
             }
         }
@@ -424,7 +582,7 @@ impl SummaryGenerator {
         Ok(())
     }
 
-    fn write_footer(&self, writer: &mut impl Write) -> io::Result<()> {
+    fn write_footer(&self, writer: &mut dyn Write) -> io::Result<()> {
         writeln!(writer, "{}", "=".repeat(self.width).blue())
     }
 
@@ -444,3 +602,197 @@ impl SummaryGenerator {
         }
     }
 }
+
+fn build_recommendations(stats: &EnhancedFileStats, metrics: &ProjectMetrics) -> Vec<String> {
+    let mut recommendations = Vec::new();
+
+    let complex_files = stats
+        .file_statistics
+        .iter()
+        .filter(|(_, s)| s.complexity.cyclomatic_complexity > 20.0)
+        .count();
+
+    if complex_files > 0 {
+        recommendations.push(format!(
+            "• Consider refactoring {} files with high complexity",
+            complex_files
+        ));
+    }
+
+    let poorly_documented = stats
+        .file_statistics
+        .iter()
+        .filter(|(_, s)| (s.comments as f64) / (s.lines as f64) < 0.1)
+        .count();
+
+    if poorly_documented > 0 {
+        recommendations.push(format!(
+            "• Add documentation to {} files with low comment coverage",
+            poorly_documented
+        ));
+    }
+
+    let large_files = stats
+        .file_statistics
+        .iter()
+        .filter(|(_, s)| s.size > 100 * 1024) // Files larger than 100KB
+        .count();
+
+    if large_files > 0 {
+        recommendations.push(format!(
+            "• Consider splitting {} large files (>100KB)",
+            large_files
+        ));
+    }
+
+    if !metrics.duplicate_groups.is_empty() {
+        let wasted: u64 = metrics
+            .duplicate_groups
+            .iter()
+            .map(DuplicateGroup::wasted_bytes)
+            .sum();
+        recommendations.push(format!(
+            "• {} duplicate files wasting {:.1} MB",
+            metrics.duplicate_groups.len(),
+            wasted as f64 / (1024.0 * 1024.0)
+        ));
+    }
+
+    if !metrics.hotspots.is_empty() {
+        recommendations.push(format!(
+            "• {} files are both high-churn and high-complexity - prioritize these for refactoring",
+            metrics.hotspots.len()
+        ));
+    }
+
+    recommendations
+}
+
+/// Renders the existing boxed, colored terminal layout.
+struct PrettyRenderer<'a> {
+    generator: &'a SummaryGenerator,
+}
+
+impl SummaryRenderer for PrettyRenderer<'_> {
+    fn render(
+        &self,
+        writer: &mut dyn Write,
+        stats: &EnhancedFileStats,
+        base_path: &str,
+        metrics: &ProjectMetrics,
+    ) -> io::Result<()> {
+        let g = self.generator;
+        g.write_header(writer)?;
+        g.write_project_info(writer, base_path)?;
+        g.write_key_metrics(writer, stats, metrics)?;
+        g.write_language_breakdown(writer, stats, metrics)?;
+        g.write_complexity_analysis(writer, stats, metrics)?;
+        g.write_file_insights(writer, stats, metrics)?;
+        g.write_recommendations(writer, stats, metrics)?;
+        g.write_footer(writer)?;
+        Ok(())
+    }
+}
+
+/// Renders the same `ProjectMetrics` snapshot as a stable JSON document, either pretty-printed
+/// or as a single line for machine piping.
+struct JsonRenderer {
+    compact: bool,
+}
+
+impl SummaryRenderer for JsonRenderer {
+    fn render(
+        &self,
+        writer: &mut dyn Write,
+        stats: &EnhancedFileStats,
+        base_path: &str,
+        metrics: &ProjectMetrics,
+    ) -> io::Result<()> {
+        let total_comments: usize = stats.language_stats.values().map(|s| s.comment_lines).sum();
+        let total_lines: usize = stats.language_stats.values().map(|s| s.lines).sum();
+
+        let mut complex_files: Vec<_> = stats.file_statistics.iter().collect();
+        complex_files.sort_by(|a, b| {
+            b.1.complexity
+                .cyclomatic_complexity
+                .partial_cmp(&a.1.complexity.cyclomatic_complexity)
+                .unwrap()
+        });
+
+        let mut largest_files: Vec<_> = stats.file_statistics.iter().collect();
+        largest_files.sort_by(|a, b| b.1.size.cmp(&a.1.size));
+
+        let data = json!({
+            "generated_at": Local::now().to_rfc3339(),
+            "base_path": base_path,
+            "files": {
+                "total": metrics.total_files,
+                "total_size_bytes": metrics.total_size,
+                "size_on_disk_bytes": metrics.size_on_disk,
+                "average_size_bytes": metrics.avg_file_size,
+                "largest_size_bytes": metrics.max_file_size,
+            },
+            "code": {
+                "total_lines": total_lines,
+                "average_lines_per_file": metrics.avg_lines_per_file,
+                "code_to_comment_ratio": if total_comments > 0 {
+                    (total_lines - total_comments) as f64 / total_comments as f64
+                } else {
+                    0.0
+                },
+            },
+            "languages": metrics.language_distribution,
+            "most_complex_files": complex_files.iter().take(5).map(|(path, s)| json!({
+                "path": path.to_string_lossy(),
+                "cyclomatic_complexity": s.complexity.cyclomatic_complexity,
+            })).collect::<Vec<_>>(),
+            "largest_files": largest_files.iter().take(5).map(|(path, s)| json!({
+                "path": path.to_string_lossy(),
+                "size_bytes": s.size,
+            })).collect::<Vec<_>>(),
+            "duplicates": {
+                "sets": metrics.duplicate_groups.iter().take(5).map(|g| json!({
+                    "paths": g.paths.iter().map(|p| p.to_string_lossy()).collect::<Vec<_>>(),
+                    "size_bytes": g.size,
+                    "wasted_bytes": g.wasted_bytes(),
+                })).collect::<Vec<_>>(),
+                "total_reclaimable_bytes": metrics.duplicate_groups.iter().map(DuplicateGroup::wasted_bytes).sum::<u64>(),
+            },
+            "git_history": metrics.git_history.as_ref().map(|git_history| json!({
+                "total_commits": git_history.total_commits,
+                "last_commit_date": git_history.last_commit_date.map(|d| d.to_rfc3339()),
+                "top_authors": git_history.top_authors,
+            })),
+            "hotspots": metrics.hotspots.iter().take(5).map(|h| json!({
+                "path": h.path.to_string_lossy(),
+                "cyclomatic_complexity": h.complexity,
+                "commits": h.commits,
+            })).collect::<Vec<_>>(),
+            "directory_hotspots": {
+                "heaviest": metrics.heaviest_directories.iter().map(|(path, rollup)| json!({
+                    "path": path.to_string_lossy(),
+                    "total_size_bytes": rollup.total_size,
+                    "file_count": rollup.file_count,
+                    "mean_cyclomatic_complexity": rollup.mean_complexity(),
+                    "last_modified": rollup.last_modified.map(|d| d.to_rfc3339()),
+                })).collect::<Vec<_>>(),
+                "most_complex": metrics.most_complex_directories.iter().map(|(path, rollup)| json!({
+                    "path": path.to_string_lossy(),
+                    "total_size_bytes": rollup.total_size,
+                    "file_count": rollup.file_count,
+                    "mean_cyclomatic_complexity": rollup.mean_complexity(),
+                    "last_modified": rollup.last_modified.map(|d| d.to_rfc3339()),
+                })).collect::<Vec<_>>(),
+            },
+            "recommendations": build_recommendations(stats, metrics),
+        });
+
+        if self.compact {
+            serde_json::to_writer(&mut *writer, &data)?;
+        } else {
+            serde_json::to_writer_pretty(&mut *writer, &data)?;
+        }
+        writeln!(writer)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Counts tokens the way a BPE tokenizer would: greedily merge the highest-ranked
+/// adjacent symbol pair in each whitespace-delimited word until no merge applies,
+/// using a rank table loaded from a vocabulary file. Falls back to a `chars / 4`
+/// approximation when no vocabulary was supplied, so callers always get a number
+/// without requiring a real tokenizer's data files.
+pub struct TokenEstimator {
+    merge_ranks: Option<HashMap<(String, String), usize>>,
+}
+
+impl Default for TokenEstimator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TokenEstimator {
+    pub fn new() -> Self {
+        Self { merge_ranks: None }
+    }
+
+    /// Loads a merge-rank table from `path`: one `token_a token_b rank` triple per
+    /// line, lower rank merging first (the usual BPE convention). Falls back to the
+    /// chars/4 approximation if the file can't be read or has no usable entries.
+    pub fn with_vocab(path: &Path) -> Self {
+        let raw = match std::fs::read_to_string(path) {
+            Ok(raw) => raw,
+            Err(e) => {
+                eprintln!(
+                    "⚠️  Failed to read token vocabulary at {}: {}",
+                    path.display(),
+                    e
+                );
+                return Self::new();
+            }
+        };
+
+        let mut merge_ranks = HashMap::new();
+        for line in raw.lines() {
+            let mut parts = line.split_whitespace();
+            if let (Some(a), Some(b), Some(rank)) = (parts.next(), parts.next(), parts.next()) {
+                if let Ok(rank) = rank.parse::<usize>() {
+                    merge_ranks.insert((a.to_string(), b.to_string()), rank);
+                }
+            }
+        }
+
+        if merge_ranks.is_empty() {
+            Self::new()
+        } else {
+            Self {
+                merge_ranks: Some(merge_ranks),
+            }
+        }
+    }
+
+    /// Estimates the number of tokens `text` would encode to.
+    pub fn count_tokens(&self, text: &str) -> usize {
+        match &self.merge_ranks {
+            Some(ranks) => text.split_whitespace().map(|word| bpe_merge_count(word, ranks)).sum::<usize>().max(1),
+            None => (text.chars().count() / 4).max(1),
+        }
+    }
+}
+
+/// Runs BPE's merge loop on a single word: repeatedly combine the lowest-rank
+/// adjacent symbol pair until none of the remaining pairs appear in the table.
+fn bpe_merge_count(word: &str, ranks: &HashMap<(String, String), usize>) -> usize {
+    let mut symbols: Vec<String> = word.chars().map(|c| c.to_string()).collect();
+
+    loop {
+        let mut best: Option<(usize, usize)> = None; // (pair index, rank)
+        for i in 0..symbols.len().saturating_sub(1) {
+            if let Some(&rank) = ranks.get(&(symbols[i].clone(), symbols[i + 1].clone())) {
+                let is_better = match best {
+                    Some((_, best_rank)) => rank < best_rank,
+                    None => true,
+                };
+                if is_better {
+                    best = Some((i, rank));
+                }
+            }
+        }
+
+        let Some((i, _)) = best else { break };
+        let merged = format!("{}{}", symbols[i], symbols[i + 1]);
+        symbols.splice(i..=i + 1, [merged]);
+    }
+
+    symbols.len()
+}
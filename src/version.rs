@@ -1,9 +1,27 @@
+use chrono::{TimeZone, Utc};
 use std::env;
 
 pub fn get_version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
 
+/// The `git describe --tags --always --dirty` string `build.rs` captured at compile
+/// time, e.g. `v1.2.0-3-gabc1234-dirty`. `"unknown"` for builds without a `.git`
+/// directory (e.g. a published crate tarball), including a `-dirty` suffix when
+/// built from an uncommitted tree.
+pub fn get_git_describe() -> &'static str {
+    option_env!("GIT_DESCRIBE").unwrap_or("unknown")
+}
+
+/// The UTC date `build.rs` ran on, formatted `YYYY-MM-DD`.
+pub fn get_build_date() -> String {
+    option_env!("BUILD_EPOCH")
+        .and_then(|s| s.parse::<i64>().ok())
+        .and_then(|epoch| Utc.timestamp_opt(epoch, 0).single())
+        .map(|dt| dt.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
 pub fn get_build_info() -> String {
     format!(
         "workspace-aggregator v{}\nAuthor: {}\nDescription: {}",
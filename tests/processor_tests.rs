@@ -5,7 +5,7 @@ use std::fs;
 #[test]
 fn test_processor_initialization() {
     // Create test directory and file
-    let test_dir = PathBuf::from("test_data");
+    let test_dir = PathBuf::from("test_data_init");
     fs::create_dir_all(&test_dir).unwrap();
     fs::write(test_dir.join("test.rs"), "fn main() {}").unwrap();
 
@@ -25,7 +25,7 @@ fn test_processor_initialization() {
 #[test]
 fn test_file_exclusion() {
     // Create test directory and files
-    let test_dir = PathBuf::from("test_data");
+    let test_dir = PathBuf::from("test_data_exclusion");
     fs::create_dir_all(&test_dir).unwrap();
     fs::write(test_dir.join("test.rs"), "fn main() {}").unwrap();
     fs::write(test_dir.join("README.md"), "# Test").unwrap();
@@ -44,6 +44,11 @@ fn test_file_exclusion() {
     // Verify only .rs file was processed
     assert_eq!(processor.processed_files(), 1);
 
+    // total_size() used to be folded in twice - once in the pre-scan, again per
+    // file as it was actually processed - so it came out ~2x the real total.
+    let expected_size = fs::metadata(test_dir.join("test.rs")).unwrap().len();
+    assert_eq!(processor.total_size(), expected_size);
+
     // Cleanup
     fs::remove_dir_all(test_dir).unwrap();
 }